@@ -0,0 +1,19 @@
+//! Feeds arbitrary bytes to every OFF reader. None of them should ever panic,
+//! no matter how malformed the input is.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miratope_core::{
+    conc::Concrete,
+    file::{off::OffStream, FromFile},
+};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(src) = std::str::from_utf8(data) {
+        let _ = Concrete::from_off(src);
+    }
+
+    let _ = Concrete::from_off_multi(data);
+    OffStream::new(data).for_each(drop);
+});
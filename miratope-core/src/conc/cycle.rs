@@ -143,6 +143,18 @@ impl CycleBuilder {
         Self(HashMap::with_capacity(capacity))
     }
 
+    /// Initializes a cycle builder from a list of edges, reserving space for a
+    /// given number of vertices beforehand. This avoids rehashing when the
+    /// iterator over the edges doesn't know its own length.
+    pub fn from_edges<T: AsRef<[usize]>, I: IntoIterator<Item = T>>(
+        edges: I,
+        capacity: usize,
+    ) -> Self {
+        let mut cycle = Self::with_capacity(capacity);
+        cycle.extend(edges);
+        cycle
+    }
+
     /// Returns `true` if no vertices have been added.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
@@ -202,42 +214,68 @@ impl CycleBuilder {
         self.remove(idx).unwrap_or_default().try_into().unwrap()
     }
 
+    /// Removes the cycle through two adjacent nodes from the graph, and returns
+    /// its nodes in cyclic order, starting at `start` and then going to `next`.
+    /// Unlike [`Self::build`], this fixes the direction in which the cycle is
+    /// traversed.
+    ///
+    /// Returns `None` if the two nodes aren't adjacent, or if the cycle through
+    /// them is incomplete. In the latter case, the nodes visited so far will
+    /// have already been removed.
+    pub fn cycle_from(&mut self, start: usize, next: usize) -> Option<Cycle> {
+        match self.0.get(&start)? {
+            Pair::Two(v0, v1) if *v0 == next || *v1 == next => {}
+            _ => return None,
+        }
+
+        let mut cycle = Cycle::with_capacity(self.len());
+        self.remove(start);
+        cycle.push(start);
+
+        let mut prev = start;
+        let mut cur = next;
+
+        // We traverse the graph until we reach the initial node again.
+        while cur != start {
+            cycle.push(cur);
+
+            // We go to whichever adjacent vertex isn't equal to the one we
+            // were previously at.
+            let (next0, next1) = self.remove(cur)?.try_into().ok()?;
+            let next = if next0 == prev { next1 } else { next0 };
+            prev = cur;
+            cur = next;
+        }
+
+        Some(cycle)
+    }
+
+    /// Returns a node with two adjacent nodes, along with one of them, under
+    /// some arbitrary order.
+    fn first_complete(&self) -> Option<(usize, usize)> {
+        self.0.iter().find_map(|(&idx, pair)| match pair {
+            Pair::Two(next, _) => Some((idx, *next)),
+            _ => None,
+        })
+    }
+
     /// Cycles through the graph, returns a vector of node indices in cyclic
     /// order.
+    ///
+    /// Any paths that don't close up into cycles, such as those through
+    /// dangling edges, are skipped. All nodes are removed from the graph
+    /// afterwards.
     pub fn build(&mut self) -> CycleList {
         let mut cycles = CycleList::new();
 
-        // While there's some vertex from which we haven't generated a cycle:
-        while let Some((&init, _)) = self.first() {
-            let mut cycle = Cycle::with_capacity(self.len());
-            let mut prev = init;
-            let mut cur = self.get_remove(prev).0;
-
-            cycle.push(cur);
-
-            // We traverse the graph, finding the next node over and over, until
-            // we reach the initial node again.
-            loop {
-                // The two candidates for the next vertex.
-                let (next0, next1) = self.get_remove(cur);
-                let next_is_next1 = next0 == prev;
-                prev = cur;
-
-                // We go to whichever adjacent vertex isn't equal to the one we
-                // were previously at.
-                let next = if next_is_next1 { next1 } else { next0 };
-                cycle.push(next);
-                cur = next;
-
-                // Whenever we reach the initial vertex, we break out of the loop.
-                if cur == init {
-                    break;
-                }
+        // While there's some node from which we can still generate a cycle:
+        while let Some((init, next)) = self.first_complete() {
+            if let Some(cycle) = self.cycle_from(init, next) {
+                cycles.push(cycle);
             }
-
-            cycles.push(cycle);
         }
 
+        self.0.clear();
         cycles
     }
 }
@@ -248,3 +286,61 @@ impl CycleList {
         edges.into_iter().collect::<CycleBuilder>().build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The edges of a square.
+    const SQUARE: [[usize; 2]; 4] = [[0, 1], [1, 2], [2, 3], [3, 0]];
+
+    /// Checks that the direction of a cycle can be fixed.
+    #[test]
+    fn cycle_from() {
+        let mut builder: CycleBuilder = SQUARE.iter().collect();
+        assert_eq!(builder.cycle_from(0, 1).unwrap().0, vec![0, 1, 2, 3]);
+        assert!(builder.is_empty());
+
+        let mut builder: CycleBuilder = SQUARE.iter().collect();
+        assert_eq!(builder.cycle_from(0, 3).unwrap().0, vec![0, 3, 2, 1]);
+    }
+
+    /// Checks that building from a list of edges is the same as pushing them
+    /// one at a time.
+    #[test]
+    fn from_edges() {
+        let mut pushed = CycleBuilder::new();
+        for edge in &SQUARE {
+            pushed.push_edge(edge);
+        }
+
+        let mut built = CycleBuilder::from_edges(&SQUARE, 4);
+        assert_eq!(built.len(), pushed.len());
+        assert_eq!(
+            built.cycle_from(0, 1).unwrap().0,
+            pushed.cycle_from(0, 1).unwrap().0
+        );
+    }
+
+    /// Checks that a cycle can't start with two non-adjacent nodes.
+    #[test]
+    fn cycle_from_non_adjacent() {
+        let mut builder: CycleBuilder = SQUARE.iter().collect();
+        assert!(builder.cycle_from(0, 2).is_none());
+        assert_eq!(builder.len(), 4);
+    }
+
+    /// Checks that a dangling edge doesn't stop the other cycles from being
+    /// built, no matter where it is.
+    #[test]
+    fn build_dangling() {
+        for i in 0..=SQUARE.len() {
+            let mut edges = SQUARE.to_vec();
+            edges.insert(i, [4, 5]);
+
+            let cycles = CycleList::from_edges(&edges);
+            assert_eq!(cycles.len(), 1);
+            assert_eq!(cycles[0].len(), 4);
+        }
+    }
+}
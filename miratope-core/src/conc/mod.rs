@@ -791,6 +791,25 @@ pub trait ConcretePolytope: Polytope {
         }
     }
 
+    /// Scales a polytope about its gravicenter, so that its farthest vertex is
+    /// at unit distance from it. Does nothing if the polytope has no vertices,
+    /// or if all of them coincide.
+    fn normalize_scale(&mut self) {
+        if let Some(gravicenter) = self.gravicenter() {
+            let radius = self
+                .vertices()
+                .iter()
+                .map(|v| (v - &gravicenter).norm())
+                .fold(0.0, f64::max);
+
+            if radius > f64::EPS {
+                for v in self.vertices_mut() {
+                    *v = &gravicenter + (&*v - &gravicenter) / radius;
+                }
+            }
+        }
+    }
+
     /// Applies a linear transformation to all vertices of a polytope.
     fn apply(mut self, m: &Matrix<f64>) -> Self {
         for v in self.vertices_mut() {
@@ -1562,6 +1581,7 @@ mod tests {
     use crate::{float::Float, Polytope};
 
     use approx::abs_diff_eq;
+    use vec_like::VecLike;
 
     /// Tests that a polytope has an expected volume.
     fn test_volume(mut poly: Concrete, volume: Option<f64>) {
@@ -1726,4 +1746,89 @@ mod tests {
             );
         }
     }
+
+    /// Checks that the faces of a cube are six 4-cycles.
+    #[test]
+    fn cube_face_cycles() {
+        let face_cycles = Concrete::cube().face_cycles();
+        assert_eq!(face_cycles.len(), 6);
+
+        for cycles in face_cycles {
+            assert_eq!(cycles.len(), 1);
+            assert_eq!(cycles[0].len(), 4);
+        }
+    }
+
+    /// Checks that adjacent faces of an octahedron go through their common edge
+    /// in opposite directions, so that every directed edge appears once.
+    #[test]
+    fn octahedron_oriented_faces() {
+        use crate::abs::Ranked;
+
+        let poly = Concrete::octahedron();
+        let mut directed_edges = std::collections::HashSet::new();
+
+        for cycle in poly.oriented_faces().unwrap() {
+            for i in 0..cycle.len() {
+                assert!(directed_edges.insert((cycle[i], cycle[(i + 1) % cycle.len()])));
+            }
+        }
+
+        assert_eq!(directed_edges.len(), 2 * poly.edge_count());
+    }
+
+    /// Checks that the tetrahemihexahedron can't be oriented.
+    #[test]
+    fn thah_oriented_faces() {
+        use crate::file::FromFile;
+
+        let poly = Concrete::from_off(
+            "OFF
+            6 7 12
+            1 0 0
+            -1 0 0
+            0 1 0
+            0 -1 0
+            0 0 1
+            0 0 -1
+            3 0 2 4
+            3 3 4 1
+            3 2 1 5
+            3 5 3 0
+            4 0 3 1 2
+            4 4 0 5 1
+            4 2 4 3 5",
+        )
+        .unwrap();
+
+        assert!(poly.oriented_faces().is_none());
+    }
+
+    /// Checks that normalizing the scale of a polytope moves its farthest
+    /// vertex to unit distance, and keeps its gravicenter fixed.
+    #[test]
+    fn normalize_scale() {
+        let mut poly = Concrete::cube();
+        poly.scale(3.0);
+        for v in poly.vertices_mut() {
+            v[0] += 1.0;
+        }
+
+        let gravicenter = poly.gravicenter().unwrap();
+        poly.normalize_scale();
+
+        assert!(abs_diff_eq!(
+            (poly.gravicenter().unwrap() - &gravicenter).norm(),
+            0.0,
+            epsilon = f64::EPS
+        ));
+
+        for v in poly.vertices() {
+            assert!(abs_diff_eq!(
+                (v - &gravicenter).norm(),
+                1.0,
+                epsilon = f64::EPS
+            ));
+        }
+    }
 }
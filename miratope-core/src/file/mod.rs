@@ -13,7 +13,7 @@ use off::OffParseError;
 use zip::result::ZipError;
 
 pub use std::io::Error as IoError;
-use std::{fs::File, string::FromUtf8Error, fmt::Display};
+use std::{fs::File, io::BufRead, string::FromUtf8Error, fmt::Display};
 
 /// Any error encountered while trying to load a polytope.
 #[derive(Debug)]
@@ -99,6 +99,46 @@ pub trait FromFile: Sized {
     /// Maybe don't load the entire file at once?
     fn from_off(src: &str) -> OffParseResult<Self>;
 
+    /// Reads a sequence of concatenated OFF files from a buffered reader. Each
+    /// of them must start with its own magic word at the start of a line. Just
+    /// as with [`Self::from_off`], anything after the end of a polytope up to
+    /// the next magic word is ignored.
+    fn from_off_multi<R: BufRead>(reader: R) -> OffParseResult<Vec<Self>> {
+        let mut polytopes = Vec::new();
+        let mut src = String::new();
+        let mut start_row = 0;
+
+        // Whether we've read any tokens since the start of the current file.
+        let mut has_tokens = false;
+
+        for (row, line) in reader.lines().enumerate() {
+            let line = line.map_err(|err| OffParseError::Io(err.kind()))?;
+            let first_token = line
+                .split('#')
+                .next()
+                .unwrap_or_default()
+                .split_whitespace()
+                .next();
+
+            // A magic word at the start of a line begins a new file.
+            if has_tokens && first_token.map_or(false, |token| token.ends_with("OFF")) {
+                polytopes.push(Self::from_off(&src).map_err(|err| offset_rows(err, start_row))?);
+                src.clear();
+                start_row = row as u32;
+            }
+
+            has_tokens |= first_token.is_some();
+            src.push_str(&line);
+            src.push('\n');
+        }
+
+        if has_tokens {
+            polytopes.push(Self::from_off(&src).map_err(|err| offset_rows(err, start_row))?);
+        }
+
+        Ok(polytopes)
+    }
+
     /// Attempts to read a GGB file. If succesful, outputs a polytope in at most
     /// 3D.
     fn from_ggb(file: File) -> GgbResult<Self>;
@@ -153,6 +193,40 @@ impl FromFile for Concrete {
     }
 }
 
+/// Shifts the position of an error in an OFF file down by a number of rows.
+/// Used when the file was read as part of a larger one.
+fn offset_rows(err: OffParseError, rows: u32) -> OffParseError {
+    use OffParseError::*;
+
+    match err {
+        UnexpectedEnding(mut pos) => {
+            pos.row += rows;
+            UnexpectedEnding(pos)
+        }
+        Parsing(mut pos) => {
+            pos.row += rows;
+            Parsing(pos)
+        }
+        Rank(mut pos) => {
+            pos.row += rows;
+            Rank(pos)
+        }
+        MagicWord(mut pos) => {
+            pos.row += rows;
+            MagicWord(pos)
+        }
+        Index(mut pos) => {
+            pos.row += rows;
+            Index(pos)
+        }
+        EmptyFace(mut pos) => {
+            pos.row += rows;
+            EmptyFace(pos)
+        }
+        Empty | Io(_) => err,
+    }
+}
+
 /// A position in a file.
 #[derive(Clone, Copy, Default, Debug)]
 pub struct Position {
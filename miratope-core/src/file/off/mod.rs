@@ -1,7 +1,13 @@
 //! The code that opens an OFF file and parses it into a polytope.
 //! Also the code that writes an OFF file of a polytope.
 
-use std::{collections::{HashMap, HashSet}, fmt::Display, io::Error as IoError, path::Path, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::{BufRead, Error as IoError},
+    path::Path,
+    str::FromStr,
+};
 
 use super::Position;
 
@@ -38,6 +44,15 @@ pub enum OffParseError {
 
     /// Didn't find the OFF magic word.
     MagicWord(Position),
+
+    /// An element referred to a subelement that doesn't exist.
+    Index(Position),
+
+    /// A face had no vertices.
+    EmptyFace(Position),
+
+    /// The underlying reader failed.
+    Io(std::io::ErrorKind),
 }
 
 impl Display for OffParseError {
@@ -48,6 +63,9 @@ impl Display for OffParseError {
             Self::Parsing(pos) => write!(f, "could not parse number at {}", pos),
             Self::Rank(pos) => write!(f, "could not read rank at {}", pos),
             Self::MagicWord(pos) => write!(f, "no \"OFF\" detected at {}", pos),
+            Self::Index(pos) => write!(f, "index out of range at {}", pos),
+            Self::EmptyFace(pos) => write!(f, "face without vertices at {}", pos),
+            Self::Io(kind) => write!(f, "could not read file: {:?}", kind),
         }
     }
 }
@@ -105,9 +123,13 @@ impl<'a> Token<'a> {
         if rank.is_empty() {
             Ok(4)
         } else {
+            // The smallest rank is that of the nullitope, -1.
             match rank.parse::<isize>() {
-                Ok(r) => Ok((r + 1) as usize),
-                Err(_) => Err(OffParseError::Rank(self.pos)),
+                Ok(r) if r >= -1 => r
+                    .checked_add(1)
+                    .map(|r| r as usize)
+                    .ok_or(OffParseError::Rank(self.pos)),
+                _ => Err(OffParseError::Rank(self.pos)),
             }
         }
     }
@@ -131,8 +153,10 @@ struct TokenIter<'a> {
 
 impl<'a> TokenIter<'a> {
     /// Returns an iterator over the OFF file, with all whitespace and comments
-    /// removed.
+    /// removed. A leading byte order mark is skipped.
     fn new(src: &'a str) -> Self {
+        let src = src.strip_prefix('\u{feff}').unwrap_or(src);
+
         Self {
             src,
             iter: src.char_indices(),
@@ -172,8 +196,9 @@ impl<'a> TokenIter<'a> {
                 break;
             }
 
-            // Advances the iterator.
-            end_idx = idx;
+            // Advances the iterator. The token ends after the current
+            // character, which might take up more than one byte.
+            end_idx = idx + c.len_utf8();
             if let Some((new_idx, new_c)) = self.iter.next() {
                 idx = new_idx;
                 c = new_c;
@@ -189,7 +214,7 @@ impl<'a> TokenIter<'a> {
         Some(if init_idx == idx {
             OffNext::Garbage
         } else {
-            OffNext::Token(&self.src[init_idx..=end_idx])
+            OffNext::Token(&self.src[init_idx..end_idx])
         })
     }
 
@@ -199,6 +224,21 @@ impl<'a> TokenIter<'a> {
             .ok_or(OffParseError::UnexpectedEnding(self.position))?
             .parse()
     }
+
+    /// Reads and parses the next token from the OFF file as an index into a
+    /// list with a given length.
+    pub fn parse_index(&mut self, len: usize) -> OffParseResult<usize> {
+        let token = self
+            .next()
+            .ok_or(OffParseError::UnexpectedEnding(self.position))?;
+        let idx = token.parse()?;
+
+        if idx < len {
+            Ok(idx)
+        } else {
+            Err(OffParseError::Index(token.pos))
+        }
+    }
 }
 
 impl<'a> Iterator for TokenIter<'a> {
@@ -243,6 +283,13 @@ impl<'a> OffReader<'a> {
         self.iter.next()
     }
 
+    /// Bounds the capacity to reserve for a given number of items read from the
+    /// OFF file. Every item takes up at least a byte, so this avoids allocating
+    /// absurd amounts of memory for malformed files.
+    fn capacity(&self, count: usize) -> usize {
+        count.min(self.src().len())
+    }
+
     /// Reads the first token from the OFF file, returns the polytope's rank.
     fn rank(&mut self) -> OffParseResult<usize> {
         self.next().ok_or(OffParseError::Empty)?.rank()
@@ -255,7 +302,7 @@ impl<'a> OffReader<'a> {
     /// This function ought to only be called when the rank is at least 2.
     fn el_nums(&mut self, rank: usize) -> OffParseResult<Vec<usize>> {
         debug_assert!(rank >= 2);
-        let mut el_nums = Vec::with_capacity(rank - 1);
+        let mut el_nums = Vec::with_capacity(self.capacity(rank - 1));
 
         // Reads entries one by one.
         for _ in 1..rank {
@@ -282,7 +329,7 @@ impl<'a> OffReader<'a> {
         dim: usize,
     ) -> OffParseResult<Vec<Point<f64>>> {
         // Reads all vertices.
-        let mut vertices = Vec::with_capacity(count);
+        let mut vertices = Vec::with_capacity(self.capacity(count));
 
         // Add each vertex to the vector.
         for _ in 0..count {
@@ -304,22 +351,31 @@ impl<'a> OffReader<'a> {
     fn parse_edges_and_faces(
         &mut self,
         rank: usize,
+        num_vertices: usize,
         num_edges: usize,
         num_faces: usize,
     ) -> OffParseResult<(SubelementList, SubelementList)> {
-        let mut edges = SubelementList::with_capacity(num_edges);
-        let mut faces = SubelementList::with_capacity(num_faces);
+        let mut edges = SubelementList::with_capacity(self.capacity(num_edges));
+        let mut faces = SubelementList::with_capacity(self.capacity(num_faces));
         let mut hash_edges = HashMap::new();
 
         // Add each face to the element list.
         for _ in 0..num_faces {
-            let face_sub_num = self.iter.parse_next()?;
+            let token = self
+                .iter
+                .next()
+                .ok_or(OffParseError::UnexpectedEnding(self.iter.position))?;
+            let face_sub_num = token.parse()?;
+            if face_sub_num == 0 {
+                return Err(OffParseError::EmptyFace(token.pos));
+            }
+
             let mut face = Subelements::new();
-            let mut face_verts = Vec::with_capacity(face_sub_num + 1);
+            let mut face_verts = Vec::with_capacity(self.capacity(face_sub_num) + 1);
 
             // Reads all vertices of the face.
             for _ in 0..face_sub_num {
-                face_verts.push(self.iter.parse_next()?);
+                face_verts.push(self.iter.parse_index(num_vertices)?);
             }
 
             // We add the first vertex to the end for simplicity.
@@ -371,18 +427,19 @@ impl<'a> OffReader<'a> {
         Ok((edges, faces))
     }
 
-    /// Parses the next set of d-elements from the OFF file.
-    fn parse_els(&mut self, num_el: usize) -> OffParseResult<SubelementList> {
-        let mut els_subs = SubelementList::with_capacity(num_el);
+    /// Parses the next set of d-elements from the OFF file, given the number of
+    /// (d - 1)-elements.
+    fn parse_els(&mut self, num_el: usize, num_subs: usize) -> OffParseResult<SubelementList> {
+        let mut els_subs = SubelementList::with_capacity(self.capacity(num_el));
 
         // Adds every d-element to the element list.
         for _ in 0..num_el {
             let el_sub_num = self.iter.parse_next()?;
-            let mut subs = Subelements::with_capacity(el_sub_num);
+            let mut subs = Subelements::with_capacity(self.capacity(el_sub_num));
 
             // Reads all sub-elements of the d-element.
             for _ in 0..el_sub_num {
-                subs.push(self.iter.parse_next()?);
+                subs.push(self.iter.parse_index(num_subs)?);
             }
 
             els_subs.push(subs);
@@ -428,15 +485,19 @@ impl<'a> OffReader<'a> {
         self.abs.push_vertices(vertices.len());
 
         // Reads edges and faces.
+        let mut num_subs = vertices.len();
         if rank >= 3 {
-            let (edges, faces) = self.parse_edges_and_faces(rank, num_elems[1], num_elems[2])?;
+            let (edges, faces) =
+                self.parse_edges_and_faces(rank, vertices.len(), num_elems[1], num_elems[2])?;
+            num_subs = faces.len();
             self.abs.push(edges);
             self.abs.push(faces);
         }
 
         // Adds all higher elements.
         for &num_el in num_elems.iter().take(rank - 1).skip(3) {
-            let subelements = self.parse_els(num_el)?;
+            let subelements = self.parse_els(num_el, num_subs)?;
+            num_subs = subelements.len();
             self.abs.push(subelements);
         }
 
@@ -452,6 +513,288 @@ impl<'a> OffReader<'a> {
     }
 }
 
+/// A single record read from an OFF file by an [`OffStream`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum OffRecord {
+    /// The rank of the polytope.
+    Rank(usize),
+
+    /// The element counts, in the order they're written in the file. That is,
+    /// the vertices come first, then the faces (or the components, in the case
+    /// of polygons), then the edges, then every higher rank.
+    ElementCounts(Vec<usize>),
+
+    /// The coordinates of a vertex.
+    Vertex(Point<f64>),
+
+    /// A face (or a component, in the case of polygons), given by the indices
+    /// of its vertices in cyclic order.
+    Face(Vec<usize>),
+
+    /// An element of rank at least 4, given by the indices of its subelements.
+    Element {
+        /// The rank of the element.
+        rank: usize,
+
+        /// The indices of the subelements.
+        subs: Vec<usize>,
+    },
+}
+
+/// The next thing an [`OffStream`] will read.
+#[derive(Clone, Copy)]
+enum StreamState {
+    /// The rank of the polytope.
+    Rank,
+
+    /// The element counts.
+    ElementCounts,
+
+    /// A given number of vertices.
+    Vertices(usize),
+
+    /// A given number of faces.
+    Faces(usize),
+
+    /// A given number of elements of a given rank.
+    Elements {
+        /// The rank of the elements.
+        rank: usize,
+
+        /// The number of elements left to read.
+        remaining: usize,
+    },
+
+    /// We've read the entire polytope.
+    Done,
+}
+
+/// Reads an OFF file from a buffered reader one record at a time, so that the
+/// file doesn't need to be loaded into memory all at once. Only the current
+/// line of the file is ever stored.
+pub struct OffStream<R: BufRead> {
+    /// The underlying reader.
+    reader: R,
+
+    /// The tokens of the current line that we haven't read yet, in reverse
+    /// order.
+    tokens: Vec<(String, Position)>,
+
+    /// The index of the next line.
+    row: u32,
+
+    /// The next thing we'll read.
+    state: StreamState,
+
+    /// The rank of the polytope.
+    rank: usize,
+
+    /// The element counts of the polytope, in the order of the file.
+    el_counts: Vec<usize>,
+}
+
+impl<R: BufRead> OffStream<R> {
+    /// Initializes a new stream from a buffered reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            tokens: Vec::new(),
+            row: 0,
+            state: StreamState::Rank,
+            rank: 0,
+            el_counts: Vec::new(),
+        }
+    }
+
+    /// Returns the position at the start of the next line.
+    fn position(&self) -> Position {
+        Position {
+            row: self.row,
+            column: 0,
+        }
+    }
+
+    /// Returns the next token along with its position, reading new lines as
+    /// needed. Returns `None` once the reader has been exhausted.
+    fn next_token(&mut self) -> OffParseResult<Option<(String, Position)>> {
+        let mut line = String::new();
+
+        while self.tokens.is_empty() {
+            line.clear();
+            if self
+                .reader
+                .read_line(&mut line)
+                .map_err(|err| OffParseError::Io(err.kind()))?
+                == 0
+            {
+                return Ok(None);
+            }
+
+            // A leading byte order mark is skipped.
+            let line = if self.row == 0 {
+                line.strip_prefix('\u{feff}').unwrap_or(&line)
+            } else {
+                line.as_str()
+            };
+
+            // Everything after a # is a comment.
+            let line = line.split('#').next().unwrap_or_default();
+            let mut token: Option<(String, Position)> = None;
+
+            for (column, c) in line.chars().enumerate() {
+                if c.is_whitespace() {
+                    self.tokens.extend(token.take());
+                } else {
+                    token
+                        .get_or_insert_with(|| {
+                            (
+                                String::new(),
+                                Position {
+                                    row: self.row,
+                                    column: column as u32,
+                                },
+                            )
+                        })
+                        .0
+                        .push(c);
+                }
+            }
+
+            self.tokens.extend(token);
+            self.tokens.reverse();
+            self.row += 1;
+        }
+
+        Ok(self.tokens.pop())
+    }
+
+    /// Reads and parses the next token from the OFF file.
+    fn parse_next<U: FromStr>(&mut self) -> OffParseResult<U> {
+        let (slice, pos) = self
+            .next_token()?
+            .ok_or_else(|| OffParseError::UnexpectedEnding(self.position()))?;
+
+        Token { slice: &slice, pos }.parse()
+    }
+
+    /// Reads a list of indices preceded by its length, and ignores the rest of
+    /// the line, which might contain things like colour info.
+    fn parse_indices(&mut self) -> OffParseResult<Vec<usize>> {
+        let len = self.parse_next()?;
+        let mut indices = Vec::new();
+
+        for _ in 0..len {
+            indices.push(self.parse_next()?);
+        }
+
+        self.tokens.clear();
+        Ok(indices)
+    }
+
+    /// Returns the state in which we read the elements of a given rank, or
+    /// `Done` if the polytope has no elements of that rank to read.
+    fn elements_state(&self, rank: usize) -> StreamState {
+        if rank < self.rank {
+            StreamState::Elements {
+                rank,
+                remaining: self.el_counts[rank - 1],
+            }
+        } else {
+            StreamState::Done
+        }
+    }
+
+    /// Reads the next record from the OFF file, or returns `None` if we've
+    /// read the entire polytope.
+    fn read_record(&mut self) -> OffParseResult<Option<OffRecord>> {
+        loop {
+            match self.state {
+                StreamState::Rank => {
+                    let (slice, pos) = self.next_token()?.ok_or(OffParseError::Empty)?;
+                    self.rank = Token { slice: &slice, pos }.rank()?;
+
+                    // Nullitopes and points don't store anything else.
+                    self.state = if self.rank < 2 {
+                        StreamState::Done
+                    } else {
+                        StreamState::ElementCounts
+                    };
+
+                    return Ok(Some(OffRecord::Rank(self.rank)));
+                }
+
+                StreamState::ElementCounts => {
+                    let mut el_counts = Vec::new();
+                    for _ in 1..self.rank {
+                        el_counts.push(self.parse_next()?);
+                    }
+
+                    self.state = StreamState::Vertices(el_counts[0]);
+                    self.el_counts = el_counts.clone();
+                    return Ok(Some(OffRecord::ElementCounts(el_counts)));
+                }
+
+                StreamState::Vertices(0) => {
+                    self.state = if self.rank >= 3 {
+                        StreamState::Faces(self.el_counts[1])
+                    } else {
+                        StreamState::Done
+                    };
+                }
+
+                StreamState::Vertices(remaining) => {
+                    let mut v = Vec::with_capacity(self.rank - 1);
+                    for _ in 1..self.rank {
+                        v.push(self.parse_next()?);
+                    }
+
+                    self.state = StreamState::Vertices(remaining - 1);
+                    return Ok(Some(OffRecord::Vertex(v.into())));
+                }
+
+                StreamState::Faces(0) => self.state = self.elements_state(4),
+
+                StreamState::Faces(remaining) => {
+                    let face = self.parse_indices()?;
+                    self.state = StreamState::Faces(remaining - 1);
+                    return Ok(Some(OffRecord::Face(face)));
+                }
+
+                StreamState::Elements { rank, remaining: 0 } => {
+                    self.state = self.elements_state(rank + 1)
+                }
+
+                StreamState::Elements { rank, remaining } => {
+                    let subs = self.parse_indices()?;
+                    self.state = StreamState::Elements {
+                        rank,
+                        remaining: remaining - 1,
+                    };
+                    return Ok(Some(OffRecord::Element { rank, subs }));
+                }
+
+                StreamState::Done => return Ok(None),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for OffStream<R> {
+    type Item = OffParseResult<OffRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_record() {
+            Ok(record) => record.map(Ok),
+
+            // We stop reading after the first error.
+            Err(err) => {
+                self.state = StreamState::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
 /*
 impl Concrete {
     /// Gets the name from the first line of an OFF file.
@@ -894,6 +1237,12 @@ mod tests {
         };
     }
 
+    /// Checks that a nullitope has the correct amount of elements.
+    #[test]
+    fn nullitope_nums() {
+        test_off!("nullitope", [1])
+    }
+
     /// Checks that a point has the correct amount of elements.
     #[test]
     fn point_nums() {
@@ -974,4 +1323,212 @@ mod tests {
     fn parse() {
         unwrap_off("OFF\n10 foo bar")
     }
+
+    /// A rank below that of the nullitope should fail.
+    #[test]
+    #[should_panic(expected = "Rank(Position { row: 0, column: 0 })")]
+    fn negative_rank() {
+        unwrap_off("-2OFF")
+    }
+
+    /// A rank too large to be stored should fail, both when reading the file
+    /// and when streaming it.
+    #[test]
+    fn huge_rank() {
+        const SRC: &str = "9223372036854775807OFF";
+
+        assert!(matches!(
+            Concrete::from_off(SRC),
+            Err(OffParseError::Rank(_))
+        ));
+        assert!(matches!(
+            OffStream::new(SRC.as_bytes()).next(),
+            Some(Err(OffParseError::Rank(_)))
+        ));
+    }
+
+    /// A huge element count shouldn't make us run out of memory.
+    #[test]
+    #[should_panic(expected = "UnexpectedEnding")]
+    fn huge_count() {
+        unwrap_off("OFF\n18446744073709551615 0 0")
+    }
+
+    /// A face without vertices should fail.
+    #[test]
+    #[should_panic(expected = "EmptyFace(Position { row: 5, column: 0 })")]
+    fn empty_face() {
+        unwrap_off("OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n0")
+    }
+
+    /// A face with a vertex that doesn't exist should fail.
+    #[test]
+    #[should_panic(expected = "Index(Position { row: 5, column: 6 })")]
+    fn index() {
+        unwrap_off("OFF\n3 1 3\n0 0 0\n1 0 0\n0 1 0\n3 0 1 3")
+    }
+
+    /// A token ending in a multibyte character should fail gracefully.
+    #[test]
+    #[should_panic(expected = "Parsing(Position { row: 1, column: 0 })")]
+    fn multibyte() {
+        unwrap_off("OFF\n1é")
+    }
+
+    /// A leading byte order mark should be skipped.
+    #[test]
+    fn bom() {
+        let src = format!("\u{feff}{}", include_str!("tet.off"));
+        test(&Concrete::from_off(&src).unwrap(), [1, 4, 6, 4, 1]);
+    }
+
+    /// Reads two tetrahedra from a single file.
+    #[test]
+    fn multi() {
+        let src = format!(
+            "{}\n\n# ---\n\n{}",
+            include_str!("tet.off"),
+            include_str!("comments.off")
+        );
+        let polytopes = Concrete::from_off_multi(src.as_bytes()).unwrap();
+
+        assert_eq!(polytopes.len(), 2);
+        for poly in &polytopes {
+            test(poly, [1, 4, 6, 4, 1]);
+        }
+    }
+
+    /// Anything after the end of a polytope up to the next magic word should
+    /// be ignored.
+    #[test]
+    fn multi_trailing() {
+        let src = format!("0OFF\n5 5\n{}", include_str!("tet.off"));
+        let polytopes = Concrete::from_off_multi(src.as_bytes()).unwrap();
+
+        assert_eq!(polytopes.len(), 2);
+        test(&polytopes[0], [1, 1]);
+        test(&polytopes[1], [1, 4, 6, 4, 1]);
+    }
+
+    /// Errors in the second of two files should be reported at the right row.
+    #[test]
+    #[should_panic(expected = "Parsing(Position { row: 3, column: 0 })")]
+    fn multi_parse() {
+        Concrete::from_off_multi("0OFF\n\nOFF\nfoo".as_bytes()).unwrap();
+    }
+
+    /// Checks that a tetrahedron is streamed record by record.
+    #[test]
+    fn stream_tet() {
+        let records = OffStream::new(include_str!("tet.off").as_bytes())
+            .collect::<OffParseResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records[0], OffRecord::Rank(4));
+        assert_eq!(records[1], OffRecord::ElementCounts(vec![4, 4, 6]));
+        assert_eq!(records.len(), 10);
+        assert!(matches!(records[5], OffRecord::Vertex(_)));
+        assert_eq!(records[6], OffRecord::Face(vec![0, 1, 2]));
+    }
+
+    /// Checks that a nullitope is streamed as a single record.
+    #[test]
+    fn stream_nullitope() {
+        let records = OffStream::new(include_str!("nullitope.off").as_bytes())
+            .collect::<OffParseResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records, vec![OffRecord::Rank(0)]);
+    }
+
+    /// Checks that comments are correctly skipped while streaming.
+    #[test]
+    fn stream_comments() {
+        let records = OffStream::new(include_str!("comments.off").as_bytes())
+            .collect::<OffParseResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 10);
+        assert_eq!(records[9], OffRecord::Face(vec![3, 1, 2]));
+    }
+
+    /// Streams a large OFF file, and checks that it's read lazily, so that
+    /// only a bounded part of it is ever held in memory.
+    #[test]
+    fn stream_large() {
+        use std::{
+            cell::Cell,
+            io::{BufReader, Read},
+            rc::Rc,
+        };
+
+        /// A reader that keeps track of how many bytes have been read from it.
+        struct CountingReader<'a> {
+            inner: &'a [u8],
+            count: Rc<Cell<usize>>,
+        }
+
+        impl<'a> Read for CountingReader<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let len = self.inner.read(buf)?;
+                self.count.set(self.count.get() + len);
+                Ok(len)
+            }
+        }
+
+        const VERTEX_COUNT: usize = 200_000;
+        const CAPACITY: usize = 1024;
+
+        // We store where the line of each vertex ends.
+        let mut src = format!("OFF\n{} 0 0\n", VERTEX_COUNT);
+        let mut line_ends = Vec::with_capacity(VERTEX_COUNT);
+        for i in 0..VERTEX_COUNT {
+            src.push_str(&format!("{} 0.5 -0.25\n", i));
+            line_ends.push(src.len());
+        }
+
+        let count = Rc::new(Cell::new(0));
+        let reader = CountingReader {
+            inner: src.as_bytes(),
+            count: Rc::clone(&count),
+        };
+
+        let mut vertex_count = 0;
+        for record in OffStream::new(BufReader::with_capacity(CAPACITY, reader)) {
+            if let OffRecord::Vertex(v) = record.unwrap() {
+                assert_eq!(v[0], vertex_count as f64);
+
+                // We never read further than a single buffer past the vertex.
+                assert!(count.get() <= line_ends[vertex_count] + CAPACITY);
+                vertex_count += 1;
+            }
+        }
+
+        assert_eq!(vertex_count, VERTEX_COUNT);
+    }
+
+    /// A leading byte order mark should be skipped while streaming.
+    #[test]
+    fn stream_bom() {
+        let src = format!("\u{feff}{}", include_str!("tet.off"));
+        let records = OffStream::new(src.as_bytes())
+            .collect::<OffParseResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(records[0], OffRecord::Rank(4));
+        assert_eq!(records.len(), 10);
+    }
+
+    /// A stream that ends early should fail, and then stop.
+    #[test]
+    fn stream_unexpected_ending() {
+        let mut stream = OffStream::new("OFF\n4 4 6\n1 1".as_bytes());
+        assert_eq!(stream.next().unwrap().unwrap(), OffRecord::Rank(4));
+        assert!(stream.next().unwrap().is_ok());
+        assert!(matches!(
+            stream.next(),
+            Some(Err(OffParseError::UnexpectedEnding(_)))
+        ));
+        assert!(stream.next().is_none());
+    }
 }
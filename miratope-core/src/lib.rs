@@ -31,13 +31,19 @@ pub mod float;
 pub mod geometry;
 pub mod group;
 
-use std::{collections::HashSet, error::Error, iter, ops::IndexMut};
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    iter,
+    ops::IndexMut,
+};
 
 use abs::{
     flag::{Flag, FlagIter, OrientedFlag, OrientedFlagIter},
     ranked::Ranks,
     Abstract, Element, ElementList, ElementMap, Ranked,
 };
+use conc::cycle::{Cycle, CycleBuilder, CycleList};
 
 use vec_like::VecLike;
 
@@ -263,6 +269,112 @@ pub trait Polytope:
         self.abs().vertex_map()
     }
 
+    /// Returns the vertices of every face of the polytope in cyclic order. Each
+    /// face is given as a [`CycleList`], since compound faces are made out of
+    /// more than one cycle.
+    fn face_cycles(&self) -> Vec<CycleList> {
+        self.get_element_list(3)
+            .map(|faces| {
+                faces
+                    .iter()
+                    .map(|face| {
+                        CycleList::from_edges(face.subs.iter().map(|&i| &self[(2, i)].subs))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the vertices of every face of a polyhedron in cyclic order,
+    /// oriented so that any two faces sharing an edge go through it in opposite
+    /// directions.
+    ///
+    /// Returns `None` if the polytope isn't of rank 4, if it has a compound
+    /// face, if some edge belongs to more than two faces, or if it isn't
+    /// orientable.
+    fn oriented_faces(&self) -> Option<Vec<Cycle>> {
+        if self.rank() != 4 {
+            return None;
+        }
+
+        let face_count = self.el_count(3);
+        let mut cycles: Vec<Option<Cycle>> = Vec::with_capacity(face_count);
+        cycles.resize_with(face_count, || None);
+
+        // The first two vertices of every face we've already oriented, and the
+        // directed edges of every face we've already gone through.
+        let mut orientations = vec![None; face_count];
+        let mut traversals: Vec<Option<HashSet<(usize, usize)>>> = vec![None; face_count];
+        let mut queue = VecDeque::new();
+
+        for init in 0..face_count {
+            if orientations[init].is_some() {
+                continue;
+            }
+
+            // We orient each connected component starting from an arbitrary
+            // edge of an arbitrary face.
+            let edge = &self[(2, *self[(3, init)].subs.iter().next()?)].subs;
+            orientations[init] = Some((edge[0], edge[1]));
+            queue.push_back(init);
+
+            while let Some(f) = queue.pop_front() {
+                let face = &self[(3, f)];
+                let (start, next) = orientations[f]?;
+                let cycle = CycleBuilder::from_edges(
+                    face.subs.iter().map(|&i| &self[(2, i)].subs),
+                    face.subs.len(),
+                )
+                .cycle_from(start, next)?;
+
+                // Compound faces consist of more than one cycle.
+                if cycle.len() != face.subs.len() {
+                    return None;
+                }
+
+                let traversal: HashSet<_> = (0..cycle.len())
+                    .map(|i| (cycle[i], cycle[(i + 1) % cycle.len()]))
+                    .collect();
+
+                for &i in &face.subs {
+                    let edge = &self[(2, i)];
+                    if edge.sups.len() > 2 {
+                        return None;
+                    }
+
+                    let (v0, v1) = (edge.subs[0], edge.subs[1]);
+                    let reversed = if traversal.contains(&(v0, v1)) {
+                        (v1, v0)
+                    } else {
+                        (v0, v1)
+                    };
+
+                    // The other face through the edge must go through it in the
+                    // opposite direction.
+                    for &g in &edge.sups {
+                        if g == f {
+                            continue;
+                        }
+
+                        if let Some(traversal) = &traversals[g] {
+                            if !traversal.contains(&reversed) {
+                                return None;
+                            }
+                        } else if orientations[g].is_none() {
+                            orientations[g] = Some(reversed);
+                            queue.push_back(g);
+                        }
+                    }
+                }
+
+                traversals[f] = Some(traversal);
+                cycles[f] = Some(cycle);
+            }
+        }
+
+        cycles.into_iter().collect()
+    }
+
     /// Gets the element with a given rank and index as a polytope, if it exists.
     fn element(&self, rank: usize, idx: usize) -> Option<Self>;
 
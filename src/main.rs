@@ -23,7 +23,7 @@ use ui::{
     MiratopePlugins,
 };
 
-use crate::mesh::Renderable;
+use crate::mesh::{RenderSettings, Renderable};
 
 mod mesh;
 mod no_cull_pipeline;
@@ -81,6 +81,7 @@ fn setup(
     mut materials: ResMut<'_, Assets<StandardMaterial>>,
     mut shaders: ResMut<'_, Assets<Shader>>,
     mut pipelines: ResMut<'_, Assets<PipelineDescriptor>>,
+    render_settings: Res<'_, RenderSettings>,
 ) {
     // Default polytope.
     let mut poly = Concrete::from_off(include_str!("default.off")).unwrap();
@@ -117,18 +118,21 @@ fn setup(
     let mut cam = Default::default();
     CameraInputEvent::reset(&mut cam_anchor, &mut cam);
 
+    // The meshes are built with the same settings used to update them later.
+    let (mesh, wireframe) = poly.mesh_and_wireframe(ProjectionType::Perspective, &render_settings);
+
     commands
         .spawn()
         // Mesh
         .insert_bundle(PbrNoBackfaceBundle {
-            mesh: meshes.add(poly.mesh(ProjectionType::Perspective)),
+            mesh: meshes.add(mesh),
             material: mesh_material,
             ..Default::default()
         })
         // Wireframe
         .with_children(|cb| {
             cb.spawn().insert_bundle(PbrNoBackfaceBundle {
-                mesh: meshes.add(poly.wireframe(ProjectionType::Perspective)),
+                mesh: meshes.add(wireframe),
                 material: wf_material,
                 ..Default::default()
             });
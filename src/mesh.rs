@@ -1,6 +1,9 @@
 //! Contains the methods that take a polytope and turn it into a mesh.
 
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
 
 use crate::ui::camera::ProjectionType;
 use crate::{Concrete, Float, Point, EPS};
@@ -12,20 +15,20 @@ use bevy::{
 use lyon::{math::point, path::Path, tessellation::*};
 use miratope_core::conc::cycle::{Cycle, CycleList};
 use miratope_core::{
-    abs::{ElementList, Ranked},
+    abs::{Element, ElementList, Ranked},
     conc::ConcretePolytope,
-    geometry::{Subspace, Vector},
+    geometry::Subspace,
+    Polytope,
 };
+use serde::{Deserialize, Serialize};
 
 use vec_like::*;
 
-/// Attempts to turn the cycle into a 2D path, which can then be given to
-/// the tessellator. Uses the specified vertex list to grab the coordinates
-/// of the vertices on the path.
+/// Attempts to flatten the cycle into 2D coordinates. Uses the specified
+/// vertex list to grab the coordinates of the vertices on the cycle.
 ///
 /// If the cycle isn't 2D, we return `None`.
-pub fn path(cycle: &Cycle, vertices: &[Point]) -> Option<Path> {
-    let mut builder = Path::builder();
+pub fn flat_cycle(cycle: &Cycle, vertices: &[Point]) -> Option<Vec<[f32; 2]>> {
     let cycle_iter = cycle.iter().map(|&idx| &vertices[idx]);
 
     // We don't bother with any polygons that aren't in 2D space.
@@ -34,39 +37,259 @@ pub fn path(cycle: &Cycle, vertices: &[Point]) -> Option<Path> {
         return None
     }
 
-    let mut flat_points = cycle_iter.map(|p| s.flatten(&p));
+    Some(
+        cycle_iter
+            .map(|p| {
+                let v = s.flatten(p);
+                [v[0] as f32, v[1] as f32]
+            })
+            .collect(),
+    )
+}
 
-    let path_point = |v: &Point| point(v[0] as f32, v[1] as f32);
+/// Turns a flattened cycle into a 2D path, which can then be given to the
+/// tessellator.
+pub fn path(flat_points: &[[f32; 2]]) -> Path {
+    let mut builder = Path::builder();
+    let mut flat_points = flat_points.iter().map(|v| point(v[0], v[1]));
 
     // We build a path from the polygon.
-    let v = flat_points.next().unwrap();
-    builder.begin(path_point(&v));
+    builder.begin(flat_points.next().unwrap());
 
     for v in flat_points {
-        builder.line_to(path_point(&v));
+        builder.line_to(v);
     }
 
     builder.end(true);
 
-    Some(builder.build())
+    builder.build()
+}
+
+/// Returns whether a flattened cycle is a convex polygon. This requires all of
+/// its corners to turn in the same direction, and for the polygon to only wind
+/// around once, which rules out star polygons.
+fn is_convex(flat_points: &[[f32; 2]]) -> bool {
+    let len = flat_points.len();
+    let mut total_angle = 0.0;
+    let mut sign = 0.0;
+
+    for i in 0..len {
+        let p0 = flat_points[i];
+        let p1 = flat_points[(i + 1) % len];
+        let p2 = flat_points[(i + 2) % len];
+
+        let u = [p1[0] - p0[0], p1[1] - p0[1]];
+        let v = [p2[0] - p1[0], p2[1] - p1[1]];
+        let cross = u[0] * v[1] - u[1] * v[0];
+        let dot = u[0] * v[0] + u[1] * v[1];
+
+        // Straight angles and changes of direction aren't convex.
+        if cross.abs() < EPS as f32 || cross * sign < 0.0 {
+            return false;
+        }
+
+        sign = cross;
+        total_angle += cross.atan2(dot);
+    }
+
+    (total_angle.abs() - std::f32::consts::TAU).abs() < 0.1
+}
+
+/// Returns the cross product of two vectors in 3D.
+fn cross(u: [Float; 3], v: [Float; 3]) -> [Float; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+/// Returns the normals of the faces of a polyhedron, in such a way that they
+/// all point outwards. Returns `None` if the polytope isn't an orientable
+/// polyhedron in 3D space.
+fn face_normals(polytope: &Concrete) -> Option<Vec<[Float; 3]>> {
+    if polytope.dim() != Some(3) {
+        return None;
+    }
+
+    let coords = |idx: usize| {
+        let p = &polytope.vertices[idx];
+        [p[0], p[1], p[2]]
+    };
+
+    let mut volume = 0.0;
+    let normals: Vec<_> = polytope
+        .oriented_faces()?
+        .into_iter()
+        .map(|cycle| {
+            // We use Newell's method, which also works for non-convex faces.
+            let mut normal = [0.0; 3];
+            for i in 0..cycle.len() {
+                let n = cross(coords(cycle[i]), coords(cycle[(i + 1) % cycle.len()]));
+                for j in 0..3 {
+                    normal[j] += n[j];
+                }
+            }
+
+            let p = coords(cycle[0]);
+            volume += (0..3).map(|j| normal[j] * p[j]).sum::<Float>();
+            normal
+        })
+        .collect();
+
+    // If the signed volume is negative, the faces are oriented inwards.
+    if volume < 0.0 {
+        Some(normals.into_iter().map(|n| n.map(|c| -c)).collect())
+    } else {
+        Some(normals)
+    }
+}
+
+/// The convention for the axes of the coordinates in the output meshes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoordConvention {
+    /// The coordinates are output as they are. This is what Bevy expects.
+    YUp,
+
+    /// The y and z coordinates are swapped.
+    ZUp,
+
+    /// The z coordinate is negated.
+    LeftHanded,
+}
+
+impl Default for CoordConvention {
+    fn default() -> Self {
+        Self::YUp
+    }
+}
+
+impl CoordConvention {
+    /// Converts a point in the default convention into this one.
+    pub fn apply(self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        match self {
+            Self::YUp => [x, y, z],
+            Self::ZUp => [x, z, y],
+            Self::LeftHanded => [x, y, -z],
+        }
+    }
+
+    /// Returns whether this convention is a reflection of the default one, in
+    /// which case the winding order of the triangles needs to be flipped for
+    /// their normals to stay consistent.
+    pub fn flips_winding(self) -> bool {
+        !matches!(self, Self::YUp)
+    }
+}
+
+/// The level of detail settings for the wireframe of a polytope.
+#[derive(Clone, Copy, Debug)]
+pub struct WireframeLod {
+    /// The number of vertices above which the wireframe gets decimated.
+    pub vertex_threshold: usize,
+
+    /// The distance below which two projected vertices are merged.
+    pub epsilon: f32,
+}
+
+/// The settings used to render a polytope.
+#[derive(Clone, Copy)]
+pub struct RenderSettings {
+    /// The rule that determines which regions of a face get filled in. This
+    /// only makes a difference for self-intersecting faces, like those of star
+    /// polytopes.
+    pub fill_rule: FillRule,
+
+    /// The maximum distance between the faces and their tessellation. Smaller
+    /// values can give more accurate triangulations, at the cost of more
+    /// triangles.
+    pub fill_tolerance: f32,
+
+    /// The convention for the axes of the output meshes.
+    pub coord_convention: CoordConvention,
+
+    /// If set, the wireframes of large polytopes are decimated.
+    pub wireframe_lod: Option<WireframeLod>,
+
+    /// If set, polytopes seen from further away than this many times their
+    /// size are only drawn as wireframes.
+    pub wireframe_distance: Option<f32>,
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        Self {
+            fill_rule: FillRule::NonZero,
+            fill_tolerance: EPS as f32,
+            coord_convention: Default::default(),
+            wireframe_lod: None,
+            wireframe_distance: None,
+        }
+    }
+}
+
+/// The integer type used for the indices of a mesh. The variants are ordered by
+/// how many vertices they can index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IndexKind {
+    /// 16-bit indices, for meshes with at most 65536 vertices.
+    U16,
+
+    /// 32-bit indices.
+    U32,
+}
+
+impl IndexKind {
+    /// Returns the smallest index type able to index a given number of
+    /// vertices.
+    fn for_len(len: usize) -> Self {
+        if len <= u16::MAX as usize + 1 {
+            Self::U16
+        } else {
+            Self::U32
+        }
+    }
+
+    /// Converts a list of indices into indices of this type.
+    fn indices(self, indices: Vec<u32>) -> Indices {
+        match self {
+            Self::U16 => Indices::U16(indices.into_iter().map(|idx| idx as u16).collect()),
+            Self::U32 => Indices::U32(indices),
+        }
+    }
 }
 
 /// Represents a triangulation of the faces of a [`Concrete`]. It stores the
 /// vertex indices that make up the triangulation of the polytope, as well as
 /// the extra vertices that may be needed to represent it.
-struct Triangulation {
+#[derive(Default)]
+pub struct Triangulation {
     /// Extra vertices that might be needed for the triangulation.
     extra_vertices: Vec<Point>,
 
     /// Indices of the vertices that make up the triangles.
     triangles: Vec<u32>,
+
+    /// The range of triangles that comes from each face, by the index of the
+    /// face. This is empty if the triangulation wasn't built from the faces.
+    face_ranges: Vec<Range<usize>>,
+
+    /// The range of extra vertices that comes from each face, by the index of
+    /// the face. This is empty if the triangulation wasn't built from the
+    /// faces.
+    face_extra_ranges: Vec<Range<usize>>,
 }
 
 impl Triangulation {
-    /// Creates a new triangulation from a polytope.
-    fn new(polytope: &Concrete) -> Self {
-        let mut extra_vertices = Vec::new();
-        let mut triangles = Vec::new();
+    /// Creates a new triangulation from a polytope. Faces that aren't planar
+    /// polygons, such as those with collinear vertices, are skipped.
+    ///
+    /// If the polytope is an orientable polyhedron in 3D space, the triangles
+    /// are oriented so that their normals point outwards.
+    ///
+    /// If the `rayon` feature is enabled, the faces are triangulated in
+    /// parallel.
+    fn new(polytope: &Concrete, settings: &RenderSettings) -> Self {
         let empty_els = ElementList::new();
 
         // Either returns a reference to the element list of a given rank, or
@@ -75,83 +298,375 @@ impl Triangulation {
 
         let edges = elements_or(2);
         let faces = elements_or(3);
+        let face_normals = face_normals(polytope);
 
-        let concrete_vertex_len = polytope.vertices.len() as u32;
+        // Triangulates a face, and orients it if possible.
+        let triangulate = |idx: usize, face: &Element| {
+            let mut triangulation = Self::face(polytope, edges, face, settings);
+            if let Some(face_normals) = &face_normals {
+                triangulation.orient(polytope, face_normals[idx]);
+            }
+
+            triangulation
+        };
 
         // We render each face separately.
-        for face in faces {
-            // We tesselate this path.
-            let cycles = CycleList::from_edges(face.subs.iter().map(|&i| &edges[i].subs));
-            for cycle in cycles {
-                if let Some(path) = path(&cycle, &polytope.vertices) {
-                    let mut geometry: VertexBuffers<_, u32> = VertexBuffers::new();
-
-                    // Configures all of the options of the tessellator.
-                    FillTessellator::new()
-                        .tessellate_with_ids(
-                            path.id_iter(),
-                            &path,
-                            None,
-                            &FillOptions::with_fill_rule(Default::default(), FillRule::NonZero)
-                                .with_tolerance(EPS as f32),
-                            &mut BuffersBuilder::new(&mut geometry, |vertex: FillVertex<'_>| {
-                                vertex.sources().next().unwrap()
-                            }),
-                        )
-                        .unwrap();
-
-                    // Maps EndpointIds to the indices in the original vertex list.
-                    let mut id_to_idx = Vec::new();
-                    for idx in cycle {
-                        id_to_idx.push(idx);
+        #[cfg(feature = "rayon")]
+        let face_triangulations: Vec<_> = {
+            use rayon::prelude::*;
+
+            faces
+                .as_slice()
+                .par_iter()
+                .enumerate()
+                .map(|(idx, face)| triangulate(idx, face))
+                .collect()
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let face_triangulations: Vec<_> = faces
+            .iter()
+            .enumerate()
+            .map(|(idx, face)| triangulate(idx, face))
+            .collect();
+
+        // We put all of the faces together.
+        let concrete_vertex_len = polytope.vertices.len() as u32;
+        let mut triangulation = Self::default();
+
+        for face_triangulation in face_triangulations {
+            triangulation.append(face_triangulation, concrete_vertex_len);
+        }
+
+        triangulation
+    }
+
+    /// Builds a triangulation of a polytope from its parts, which might have
+    /// been computed beforehand. The indices of the triangles refer to the
+    /// vertices of the polytope followed by the extra vertices.
+    ///
+    /// Returns `None` if any of the indices is out of range.
+    pub fn from_parts(
+        polytope: &Concrete,
+        extra_vertices: Vec<Point>,
+        triangles: Vec<[usize; 3]>,
+    ) -> Option<Self> {
+        let vertex_count = polytope.vertices.len() + extra_vertices.len();
+        if triangles.iter().flatten().any(|&idx| idx >= vertex_count) {
+            return None;
+        }
+
+        Some(Self {
+            extra_vertices,
+            triangles: triangles
+                .into_iter()
+                .flatten()
+                .map(|idx| idx as u32)
+                .collect(),
+            face_ranges: Vec::new(),
+            face_extra_ranges: Vec::new(),
+        })
+    }
+
+    /// Returns whether all of the indices of the triangles are in range for
+    /// the vertices of a given polytope followed by the extra vertices.
+    fn fits(&self, polytope: &Concrete) -> bool {
+        let vertex_count = polytope.vertices.len() + self.extra_vertex_count();
+        self.triangles
+            .iter()
+            .all(|&idx| (idx as usize) < vertex_count)
+    }
+
+    /// Triangulates a single face of a polytope. The indices of the extra
+    /// vertices start right after the vertices of the polytope, as if this was
+    /// the only face.
+    fn face(
+        polytope: &Concrete,
+        edges: &ElementList,
+        face: &Element,
+        settings: &RenderSettings,
+    ) -> Self {
+        let mut extra_vertices = Vec::new();
+        let mut triangles = Vec::new();
+        let concrete_vertex_len = polytope.vertices.len() as u32;
+
+        // We tesselate this path.
+        let cycles = CycleList::from_edges(face.subs.iter().map(|&i| &edges[i].subs));
+        for cycle in cycles {
+            if let Some(flat_points) = flat_cycle(&cycle, &polytope.vertices) {
+                // Convex faces can be triangulated as a fan, without having
+                // to call the tessellator.
+                if is_convex(&flat_points) {
+                    for i in 1..cycle.len() - 1 {
+                        triangles.push(cycle[0] as u32);
+                        triangles.push(cycle[i] as u32);
+                        triangles.push(cycle[i + 1] as u32);
                     }
 
-                    // We map the output vertices to the original ones, and add any
-                    // extra vertices that may be needed.
-                    let mut vertex_hash = HashMap::new();
+                    continue;
+                }
+
+                let path = path(&flat_points);
+                let mut geometry: VertexBuffers<_, u32> = VertexBuffers::new();
+
+                // Configures all of the options of the tessellator.
+                let result = FillTessellator::new().tessellate_with_ids(
+                    path.id_iter(),
+                    &path,
+                    None,
+                    &FillOptions::with_fill_rule(Default::default(), settings.fill_rule)
+                        .with_tolerance(settings.fill_tolerance),
+                    &mut BuffersBuilder::new(&mut geometry, |vertex: FillVertex<'_>| {
+                        vertex.sources().next().unwrap()
+                    }),
+                );
+
+                // Degenerate faces are skipped instead of crashing the app.
+                if result.is_err() {
+                    println!("WARNING: Skipped a face that couldn't be tessellated!");
+                    continue;
+                }
+
+                // Maps EndpointIds to the indices in the original vertex list.
+                let mut id_to_idx = Vec::new();
+                for idx in cycle {
+                    id_to_idx.push(idx);
+                }
+
+                // We map the output vertices to the original ones, and add any
+                // extra vertices that may be needed.
+                let mut vertex_hash = HashMap::new();
 
-                    for (new_id, vertex_source) in geometry.vertices.into_iter().enumerate() {
-                        let new_id = new_id as u32;
+                for (new_id, vertex_source) in geometry.vertices.into_iter().enumerate() {
+                    let new_id = new_id as u32;
 
-                        match vertex_source {
-                            // This is one of the concrete vertices of the polytope.
-                            VertexSource::Endpoint { id } => {
-                                vertex_hash.insert(new_id, id_to_idx[id.to_usize()] as u32);
-                            }
+                    match vertex_source {
+                        // This is one of the concrete vertices of the polytope.
+                        VertexSource::Endpoint { id } => {
+                            vertex_hash.insert(new_id, id_to_idx[id.to_usize()] as u32);
+                        }
 
-                            // This is a new vertex that has been added to the tesselation.
-                            VertexSource::Edge { from, to, t } => {
-                                let from = &polytope.vertices[id_to_idx[from.to_usize()]];
-                                let to = &polytope.vertices[id_to_idx[to.to_usize()]];
+                        // This is a new vertex that has been added to the tesselation.
+                        VertexSource::Edge { from, to, t } => {
+                            let from = &polytope.vertices[id_to_idx[from.to_usize()]];
+                            let to = &polytope.vertices[id_to_idx[to.to_usize()]];
 
-                                let t = t as Float;
-                                let p = from * (1.0 - t) + to * t;
+                            let t = t as Float;
+                            let p = from * (1.0 - t) + to * t;
 
-                                vertex_hash
-                                    .insert(new_id, concrete_vertex_len + extra_vertices.len() as u32);
+                            vertex_hash
+                                .insert(new_id, concrete_vertex_len + extra_vertices.len() as u32);
 
-                                extra_vertices.push(p);
-                            }
+                            extra_vertices.push(p);
                         }
                     }
+                }
 
-                    // Add all of the new indices we've found onto the triangle vector.
-                    for new_idx in geometry
-                        .indices
-                        .iter()
-                        .map(|idx| *vertex_hash.get(idx).unwrap())
-                    {
-                        triangles.push(new_idx);
-                    }
+                // Add all of the new indices we've found onto the triangle vector.
+                for new_idx in geometry
+                    .indices
+                    .iter()
+                    .map(|idx| *vertex_hash.get(idx).unwrap())
+                {
+                    triangles.push(new_idx);
                 }
+            } else {
+                println!("WARNING: Skipped a face that isn't planar!");
             }
         }
 
         Self {
+            face_ranges: vec![0..triangles.len() / 3],
+            face_extra_ranges: vec![0..extra_vertices.len()],
             extra_vertices,
             triangles,
         }
     }
+
+    /// Flips the triangles of a single face so that their normals point in the
+    /// same direction as a given normal.
+    fn orient(&mut self, polytope: &Concrete, normal: [Float; 3]) {
+        let concrete_vertex_len = polytope.vertices.len();
+        let extra_vertices = &self.extra_vertices;
+
+        let coords = |idx: u32| {
+            let idx = idx as usize;
+            let p = if idx < concrete_vertex_len {
+                &polytope.vertices[idx]
+            } else {
+                &extra_vertices[idx - concrete_vertex_len]
+            };
+
+            [p[0], p[1], p[2]]
+        };
+
+        for triangle in self.triangles.chunks_exact_mut(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(coords);
+            let u = [0, 1, 2].map(|i| b[i] - a[i]);
+            let v = [0, 1, 2].map(|i| c[i] - a[i]);
+            let n = cross(u, v);
+
+            if (0..3).map(|i| n[i] * normal[i]).sum::<Float>() < 0.0 {
+                triangle.swap(1, 2);
+            }
+        }
+    }
+
+    /// Appends the triangulation of another set of faces of the same polytope
+    /// to this one, shifting the indices of its extra vertices accordingly.
+    fn append(&mut self, mut other: Self, concrete_vertex_len: u32) {
+        let offset = self.extra_vertices.len() as u32;
+        let triangle_offset = self.triangle_count();
+
+        self.face_ranges.extend(
+            other
+                .face_ranges
+                .into_iter()
+                .map(|range| range.start + triangle_offset..range.end + triangle_offset),
+        );
+        self.face_extra_ranges.extend(
+            other
+                .face_extra_ranges
+                .into_iter()
+                .map(|range| range.start + offset as usize..range.end + offset as usize),
+        );
+
+        for idx in &mut other.triangles {
+            if *idx >= concrete_vertex_len {
+                *idx += offset;
+            }
+        }
+
+        self.extra_vertices.append(&mut other.extra_vertices);
+        self.triangles.append(&mut other.triangles);
+    }
+
+    /// Returns the triangulation of a single face, with the indices of its
+    /// extra vertices starting right after the vertices of the polytope, as if
+    /// it was the only face.
+    fn face_part(&self, face_idx: usize, concrete_vertex_len: u32) -> Self {
+        let range = self.face_ranges[face_idx].clone();
+        let extra_range = self.face_extra_ranges[face_idx].clone();
+        let offset = extra_range.start as u32;
+
+        Self {
+            extra_vertices: self.extra_vertices[extra_range.clone()].to_vec(),
+            triangles: self.triangles[range.start * 3..range.end * 3]
+                .iter()
+                .map(|&idx| {
+                    if idx >= concrete_vertex_len {
+                        idx - offset
+                    } else {
+                        idx
+                    }
+                })
+                .collect(),
+            face_ranges: vec![0..range.len()],
+            face_extra_ranges: vec![0..extra_range.len()],
+        }
+    }
+
+    /// Moves a vertex of a polytope, and updates the triangulation by only
+    /// triangulating the faces that contain it again. The rest of the faces
+    /// are kept as they were, so the settings should be the same that the
+    /// triangulation was built with.
+    fn update_vertex(
+        &mut self,
+        polytope: &mut Concrete,
+        index: usize,
+        new: Point,
+        settings: &RenderSettings,
+    ) {
+        polytope.vertices[index] = new;
+        let polytope: &Concrete = polytope;
+
+        let empty_els = ElementList::new();
+        let elements_or = |r| polytope.get_element_list(r).unwrap_or(&empty_els);
+        let edges = elements_or(2);
+        let faces = elements_or(3);
+
+        // If we don't know which triangles come from which face, we have to
+        // triangulate everything again.
+        if self.face_ranges.len() != faces.len() {
+            *self = Self::new(polytope, settings);
+            return;
+        }
+
+        // Moving a vertex might change the orientation of any face, so all of
+        // them are oriented again.
+        let face_normals = face_normals(polytope);
+        let concrete_vertex_len = polytope.vertices.len() as u32;
+        let mut triangulation = Self::default();
+
+        for (idx, face) in faces.iter().enumerate() {
+            let mut face_triangulation = if face
+                .subs
+                .iter()
+                .any(|&edge| edges[edge].subs.contains(&index))
+            {
+                Self::face(polytope, edges, face, settings)
+            } else {
+                self.face_part(idx, concrete_vertex_len)
+            };
+
+            if let Some(face_normals) = &face_normals {
+                face_triangulation.orient(polytope, face_normals[idx]);
+            }
+
+            triangulation.append(face_triangulation, concrete_vertex_len);
+        }
+
+        *self = triangulation;
+    }
+
+    /// Returns the extra vertices that were added by the triangulation. These
+    /// come right after the vertices of the polytope in the index space used
+    /// by [`Self::triangles`].
+    pub fn extra_vertices(&self) -> &[Point] {
+        &self.extra_vertices
+    }
+
+    /// Returns the number of extra vertices that were added by the
+    /// triangulation. These are mostly the intersections of the edges of
+    /// self-intersecting faces.
+    pub fn extra_vertex_count(&self) -> usize {
+        self.extra_vertices.len()
+    }
+
+    /// Returns the number of triangles in the triangulation.
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len() / 3
+    }
+
+    /// Returns an iterator over the triangles of the triangulation, as triples
+    /// of indices into the vertices of the polytope followed by the extra
+    /// vertices.
+    pub fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.triangles
+            .chunks_exact(3)
+            .map(|t| [t[0] as usize, t[1] as usize, t[2] as usize])
+    }
+
+    /// Returns the vertices of the polytope, followed by the extra vertices of
+    /// the triangulation. This is the index space used by [`Self::triangles`].
+    pub fn all_vertices<'a>(
+        &'a self,
+        polytope: &'a Concrete,
+    ) -> impl Iterator<Item = &'a Point> + 'a {
+        polytope.vertices.iter().chain(self.extra_vertices())
+    }
+}
+
+/// The data of a [`Triangulation`] in a form that can be stored, so that the
+/// faces of a polytope don't need to be triangulated again after loading it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TriangulationData {
+    /// Extra vertices that might be needed for the triangulation.
+    pub extra_vertices: Vec<Point>,
+
+    /// The triangles, as triples of indices into the vertices of the polytope
+    /// followed by the extra vertices.
+    pub triangles: Vec<[usize; 3]>,
 }
 
 /// Generates normals from a set of vertices by just projecting radially from
@@ -171,6 +686,67 @@ fn normals(vertices: &[[f32; 3]]) -> Vec<[f32; 3]> {
         .collect()
 }
 
+/// Returns the unit normal of a triangle, or the zero vector if the triangle is
+/// degenerate.
+fn triangle_normal([a, b, c]: [[f32; 3]; 3]) -> [f32; 3] {
+    let u = [0, 1, 2].map(|i| b[i] - a[i]);
+    let v = [0, 1, 2].map(|i| c[i] - a[i]);
+    let n = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+
+    let sq_norm = n[0] * n[0] + n[1] * n[1] + n[2] * n[2];
+    if sq_norm < EPS as f32 {
+        [0.0, 0.0, 0.0]
+    } else {
+        let norm = sq_norm.sqrt();
+        n.map(|c| c / norm)
+    }
+}
+
+/// Returns the index type shared by all of the meshes of a polytope, so that
+/// they never disagree. This is chosen from the largest number of vertices in
+/// any of the meshes.
+///
+/// To avoid triangulating the faces, we bound the number of extra vertices of
+/// the triangulation by the number of pairs of edges that could cross within
+/// each face. This errs on the side of 32-bit indices.
+fn index_kind(poly: &Concrete) -> IndexKind {
+    let extra_vertex_bound = poly.get_element_list(3).map_or(0, |faces| {
+        faces
+            .iter()
+            .map(|face| face.subs.len())
+            .fold(0usize, |bound, len| {
+                bound.saturating_add(len.saturating_mul(len.saturating_sub(1)) / 2)
+            })
+    });
+
+    IndexKind::for_len(
+        poly.vertex_count()
+            .saturating_add(extra_vertex_bound)
+            .max(poly.edge_count()),
+    )
+}
+
+/// Returns the least and greatest corners of the bounding box of a set of
+/// projected vertices, or `None` if there are none.
+fn bounding_box(vertices: &[[f32; 3]]) -> Option<([f32; 3], [f32; 3])> {
+    let (first, rest) = vertices.split_first()?;
+
+    let mut min = *first;
+    let mut max = *first;
+    for v in rest {
+        for i in 0..3 {
+            min[i] = min[i].min(v[i]);
+            max[i] = max[i].max(v[i]);
+        }
+    }
+
+    Some((min, max))
+}
+
 /// Returns an empty mesh.
 fn empty_mesh() -> Mesh {
     let mut mesh = Mesh::new(PrimitiveTopology::LineList);
@@ -182,109 +758,1352 @@ fn empty_mesh() -> Mesh {
     mesh
 }
 
-/// Gets the coordinates of the vertices, after projecting down into 3D.
+/// Gets the coordinates of the vertices, after projecting down into 3D and
+/// converting them into the coordinate convention of the settings.
 fn vertex_coords<'a, I: Iterator<Item = &'a Point>>(
     poly: &Concrete,
     vertices: I,
     projection_type: ProjectionType,
+    settings: &RenderSettings,
 ) -> Vec<[f32; 3]> {
     let dim = poly.dim_or();
 
     // Returns the ith coordinate of p, or 0 if it doesn't exist.
     let coord = |p: &Point, i: usize| p.get(i).copied().unwrap_or_default();
 
+    // All of the math is done with floats of type `Float`, and we only convert
+    // into what Bevy expects at the very end. Non-finite coordinates, which
+    // can come from the file itself or from overflowing an `f32`, are sent to
+    // the origin.
+    let convention = settings.coord_convention;
+    let output = |v: [Float; 3]| {
+        convention.apply(v.map(|c| {
+            let c = c as f32;
+            if c.is_finite() {
+                c
+            } else {
+                0.0
+            }
+        }))
+    };
+
     // If the polytope is at most 3D, we just embed it into 3D space.
     if projection_type.is_orthogonal() || dim <= 3 {
-        vertices.map(|p| [0, 1, 2].map(|i| coord(p, i) as f32)).collect()
+        vertices
+            .map(|p| output([0, 1, 2].map(|i| coord(p, i))))
+            .collect()
     }
     // Else, we project it down.
     else {
-        // Distance from the projection planes.
-        let mut direction = Vector::zeros(dim);
-        direction[3] = 1.0;
-
-        let (min, max) = poly.minmax(direction).unwrap();
-        let dist = (min as f32 - 1.0).abs().max(max as f32 + 1.0).abs();
+        // Distance from the projection planes. Non-finite coordinates are
+        // skipped, as they would otherwise throw off every other vertex.
+        let (min, max) = poly
+            .vertices
+            .iter()
+            .map(|p| coord(p, 3))
+            .filter(|w| w.is_finite())
+            .fold(None, |minmax: Option<(Float, Float)>, w| {
+                Some(minmax.map_or((w, w), |(min, max)| (min.min(w), max.max(w))))
+            })
+            .unwrap_or_default();
+        let dist = (min - 1.0).abs().max(max + 1.0).abs();
 
         vertices
             .map(|p| {
                 // We scale the first three coordinates accordingly.
-                let factor: f32 = p.iter().skip(3).map(|&x| x as f32 + dist).product();
-                [0, 1, 2].map(|i| coord(p, i) as f32 / factor)
+                let mut factor: Float = p.iter().skip(3).map(|&x| x + dist).product();
+
+                // Vertices at the projection singularity would otherwise end up
+                // with infinite or NaN coordinates. We send them far away
+                // instead. Non-finite factors are taken care of on output.
+                if factor.abs() < EPS {
+                    factor = EPS.copysign(factor);
+                }
+
+                output([0, 1, 2].map(|i| coord(p, i) / factor))
             })
             .collect()
     }
 }
 
-/// A trait for a polytope for which we can build a mesh.
-pub trait Renderable: ConcretePolytope {
-    /// Builds the mesh of a polytope.
-    fn mesh(&self, projection_type: ProjectionType) -> Mesh {
-        // If there's no vertices, returns an empty mesh.
-        if self.vertex_count() == 0 {
-            return empty_mesh();
-        }
+/// Builds the mesh of a polytope, given the already projected coordinates of
+/// its vertices.
+fn mesh_with_coords(
+    poly: &Concrete,
+    mut vertices: Vec<[f32; 3]>,
+    triangulation: Triangulation,
+    projection_type: ProjectionType,
+    settings: &RenderSettings,
+) -> Mesh {
+    // Projects the vertices of the triangulation.
+    vertices.append(&mut vertex_coords(
+        poly,
+        triangulation.extra_vertices.iter(),
+        projection_type,
+        settings,
+    ));
 
-        // Triangulates the polytope's faces, projects the vertices of both the
-        // polytope and the triangulation.
-        let triangulation = Triangulation::new(self.con());
-        let vertices = vertex_coords(
-            self.con(),
-            self.vertices()
-                .iter()
-                .chain(triangulation.extra_vertices.iter()),
-            projection_type,
-        );
+    // Keeps the normals consistent under reflections.
+    let mut triangles = triangulation.triangles;
+    if settings.coord_convention.flips_winding() {
+        for triangle in triangles.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+    }
 
-        // Builds the actual mesh.
-        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
-        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 1.0]; vertices.len()]);
-        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals(&vertices));
-        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
-        mesh.set_indices(Some(Indices::U32(triangulation.triangles)));
+    // Builds the actual mesh.
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 1.0]; vertices.len()]);
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals(&vertices));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+    // Stored triangulations might have more extra vertices than we expect.
+    let index_kind = index_kind(poly).max(IndexKind::for_len(vertices.len()));
+    mesh.set_indices(Some(index_kind.indices(triangles)));
 
-        mesh
-    }
+    mesh
+}
 
-    /// Builds the wireframe of a polytope.
-    fn wireframe(&self, projection_type: ProjectionType) -> Mesh {
-        let vertex_count = self.vertex_count();
+/// Builds the wireframe of a polytope, given the already projected coordinates
+/// of its vertices.
+fn wireframe_with_coords(
+    poly: &Concrete,
+    vertices: Vec<[f32; 3]>,
+    settings: &RenderSettings,
+) -> Mesh {
+    let vertex_count = vertices.len();
+    let mut indices = Vec::with_capacity(poly.edge_count() * 2);
 
-        // If there's no vertices, returns an empty mesh.
-        if vertex_count == 0 {
-            return empty_mesh();
-        }
+    // If the polytope is large enough, we merge nearby vertices.
+    let representatives = settings
+        .wireframe_lod
+        .filter(|lod| vertex_count > lod.vertex_threshold)
+        .map(|lod| merge_close_vertices(&vertices, lod.epsilon));
+    let mut merged_edges = HashSet::new();
 
-        let edge_count = self.edge_count();
+    // Adds the edges to the wireframe.
+    if let Some(edges) = poly.get_element_list(2) {
+        for edge in edges {
+            debug_assert_eq!(
+                edge.subs.len(),
+                2,
+                "Edge must have exactly 2 elements, found {}.",
+                edge.subs.len()
+            );
 
-        // We add a single vertex so that Miratope doesn't crash.
-        let vertices = vertex_coords(self.con(), self.vertices().iter(), projection_type);
-        let mut indices = Vec::with_capacity(edge_count * 2);
+            let mut v0 = edge.subs[0];
+            let mut v1 = edge.subs[1];
 
-        // Adds the edges to the wireframe.
-        if let Some(edges) = self.get_element_list(2) {
-            for edge in edges {
-                debug_assert_eq!(
-                    edge.subs.len(),
-                    2,
-                    "Edge must have exactly 2 elements, found {}.",
-                    edge.subs.len()
-                );
+            // Skips any edges that collapsed into a point or into another edge.
+            if let Some(representatives) = &representatives {
+                v0 = representatives[v0];
+                v1 = representatives[v1];
 
-                indices.push(edge.subs[0] as u16);
-                indices.push(edge.subs[1] as u16);
+                if v0 == v1 || !merged_edges.insert((v0.min(v1), v0.max(v1))) {
+                    continue;
+                }
             }
+
+            indices.push(v0 as u32);
+            indices.push(v1 as u32);
         }
+    }
 
-        // Sets the mesh attributes.
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList);
-        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals(&vertices));
-        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
-        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0; 2]; vertex_count]);
-        mesh.set_indices(Some(Indices::U16(indices)));
+    // Sets the mesh attributes.
+    let mut mesh = Mesh::new(PrimitiveTopology::LineList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals(&vertices));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0; 2]; vertex_count]);
+    mesh.set_indices(Some(index_kind(poly).indices(indices)));
 
-        mesh
-    }
+    mesh
 }
 
-impl<U: ConcretePolytope> Renderable for U {}
+/// Merges the projected vertices that are within a given distance of each
+/// other, by snapping them into a grid. Returns the index of the vertex each
+/// vertex was merged into.
+fn merge_close_vertices(vertices: &[[f32; 3]], epsilon: f32) -> Vec<usize> {
+    let mut cells = HashMap::new();
+
+    vertices
+        .iter()
+        .enumerate()
+        .map(|(idx, v)| {
+            *cells
+                .entry(v.map(|c| (c / epsilon).round() as i64))
+                .or_insert(idx)
+        })
+        .collect()
+}
+
+/// Builds the edge midpoint mesh of a polytope, given the already projected
+/// coordinates of its vertices.
+fn edge_midpoints_with_coords(poly: &Concrete, vertices: &[[f32; 3]]) -> Mesh {
+    let edges = match poly.get_element_list(2) {
+        Some(edges) if !edges.is_empty() => edges,
+        _ => return empty_mesh(),
+    };
+
+    // We take the midpoints after projecting, so that they actually lie on
+    // the edges of the wireframe.
+    let midpoints: Vec<[f32; 3]> = edges
+        .iter()
+        .map(|edge| {
+            let v0 = vertices[edge.subs[0]];
+            let v1 = vertices[edge.subs[1]];
+            [0, 1, 2].map(|i| (v0[i] + v1[i]) / 2.0)
+        })
+        .collect();
+    let midpoint_count = midpoints.len();
+
+    // Sets the mesh attributes.
+    let mut mesh = Mesh::new(PrimitiveTopology::PointList);
+    mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals(&midpoints));
+    mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, midpoints);
+    mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0; 2]; midpoint_count]);
+    mesh.set_indices(Some(
+        index_kind(poly).indices((0..midpoint_count as u32).collect()),
+    ));
+
+    mesh
+}
+
+/// A trait for a polytope for which we can build a mesh.
+pub trait Renderable: ConcretePolytope {
+    /// Projects the vertices of the polytope down into 3D.
+    fn vertex_coords(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> Vec<[f32; 3]> {
+        vertex_coords(
+            self.con(),
+            self.vertices().iter(),
+            projection_type,
+            settings,
+        )
+    }
+
+    /// Returns the least and greatest corners of the bounding box of the
+    /// projected vertices of the polytope, or `None` if it has no vertices.
+    fn bounds(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> Option<([f32; 3], [f32; 3])> {
+        bounding_box(&self.vertex_coords(projection_type, settings))
+    }
+
+    /// Returns the average of the projected vertices of the polytope, or `None`
+    /// if it has no vertices. The extra vertices of the triangulation aren't
+    /// taken into account.
+    fn centroid(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> Option<[f32; 3]> {
+        let vertices = self.vertex_coords(projection_type, settings);
+        if vertices.is_empty() {
+            return None;
+        }
+
+        let mut sum = [0.0; 3];
+        for v in &vertices {
+            for i in 0..3 {
+                sum[i] += v[i];
+            }
+        }
+
+        let len = vertices.len() as f32;
+        Some(sum.map(|c| c / len))
+    }
+
+    /// Triangulates the faces of the polytope with the default settings.
+    fn triangulation(&self) -> Triangulation {
+        self.triangulation_with(&Default::default())
+    }
+
+    /// Triangulates the faces of the polytope with the given settings.
+    fn triangulation_with(&self, settings: &RenderSettings) -> Triangulation {
+        Triangulation::new(self.con(), settings)
+    }
+
+    /// Moves a vertex of the polytope, and updates a triangulation of it by
+    /// only triangulating the faces that contain the vertex again. This is
+    /// much faster than calling [`Self::triangulation_with`] when editing a
+    /// large polytope.
+    fn update_vertex(
+        &mut self,
+        triangulation: &mut Triangulation,
+        index: usize,
+        new: Point,
+        settings: &RenderSettings,
+    ) {
+        triangulation.update_vertex(self.con_mut(), index, new, settings);
+    }
+
+    /// Builds the mesh of a polytope.
+    fn mesh(&self, projection_type: ProjectionType, settings: &RenderSettings) -> Mesh {
+        // If there's no vertices, returns an empty mesh.
+        if self.vertex_count() == 0 {
+            return empty_mesh();
+        }
+
+        let triangulation = self.triangulation_with(settings);
+        mesh_with_coords(
+            self.con(),
+            self.vertex_coords(projection_type, settings),
+            triangulation,
+            projection_type,
+            settings,
+        )
+    }
+
+    /// Builds the mesh of a polytope from a triangulation of its faces that
+    /// was computed beforehand, without triangulating it again. Returns `None`
+    /// if any of the indices of the triangulation is out of range for this
+    /// polytope, which means it was computed for a different one.
+    fn mesh_from(
+        &self,
+        triangulation: Triangulation,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> Option<Mesh> {
+        if !triangulation.fits(self.con()) {
+            return None;
+        }
+
+        // If there's no vertices, returns an empty mesh.
+        if self.vertex_count() == 0 {
+            return Some(empty_mesh());
+        }
+
+        Some(mesh_with_coords(
+            self.con(),
+            self.vertex_coords(projection_type, settings),
+            triangulation,
+            projection_type,
+            settings,
+        ))
+    }
+
+    /// Triangulates the faces of the polytope with the given settings, and
+    /// returns the data of the triangulation so that it can be stored.
+    fn triangulation_data(&self, settings: &RenderSettings) -> TriangulationData {
+        let triangulation = self.triangulation_with(settings);
+
+        TriangulationData {
+            triangles: triangulation.triangles().collect(),
+            extra_vertices: triangulation.extra_vertices,
+        }
+    }
+
+    /// Builds the mesh of a polytope from stored triangulation data. Returns
+    /// `None` if the data doesn't match the polytope.
+    fn mesh_from_data(
+        &self,
+        data: TriangulationData,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> Option<Mesh> {
+        let triangulation =
+            Triangulation::from_parts(self.con(), data.extra_vertices, data.triangles)?;
+        self.mesh_from(triangulation, projection_type, settings)
+    }
+
+    /// Builds the wireframe of a polytope.
+    fn wireframe(&self, projection_type: ProjectionType, settings: &RenderSettings) -> Mesh {
+        // If there's no vertices, returns an empty mesh.
+        if self.vertex_count() == 0 {
+            return empty_mesh();
+        }
+
+        wireframe_with_coords(
+            self.con(),
+            self.vertex_coords(projection_type, settings),
+            settings,
+        )
+    }
+
+    /// Builds either the mesh or the wireframe of a polytope, depending on how
+    /// far away the camera is in comparison to its size. The threshold is set
+    /// in [`RenderSettings::wireframe_distance`].
+    fn lod_mesh(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+        camera_distance: f32,
+    ) -> Mesh {
+        // We only project the vertices once.
+        let vertices = self.vertex_coords(projection_type, settings);
+        let (min, max) = match bounding_box(&vertices) {
+            Some(bounds) => bounds,
+            None => return empty_mesh(),
+        };
+
+        // The length of the diagonal of the bounding box.
+        let size = [0, 1, 2]
+            .map(|i| (max[i] - min[i]) * (max[i] - min[i]))
+            .iter()
+            .sum::<f32>()
+            .sqrt();
+
+        match settings.wireframe_distance {
+            // The wireframe doesn't need the faces to be triangulated.
+            Some(ratio) if camera_distance > ratio * size => {
+                wireframe_with_coords(self.con(), vertices, settings)
+            }
+            _ => {
+                let triangulation = self.triangulation_with(settings);
+                mesh_with_coords(
+                    self.con(),
+                    vertices,
+                    triangulation,
+                    projection_type,
+                    settings,
+                )
+            }
+        }
+    }
+
+    /// Builds the mesh of the elements of the polytope up to a given rank,
+    /// without any of its faces. Since any higher element is drawn through its
+    /// edges, everything from rank 2 onwards gives the wireframe.
+    fn skeleton(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+        max_rank: usize,
+    ) -> Mesh {
+        match max_rank {
+            0 => empty_mesh(),
+            1 => self.vertices_mesh(projection_type, settings),
+            _ => self.wireframe(projection_type, settings),
+        }
+    }
+
+    /// Builds the mesh of a polytope without any shared vertices, so that every
+    /// triangle gets its own flat normal.
+    fn flat_mesh(&self, projection_type: ProjectionType, settings: &RenderSettings) -> Mesh {
+        // If there's no vertices, returns an empty mesh.
+        if self.vertex_count() == 0 {
+            return empty_mesh();
+        }
+
+        let triangulation = self.triangulation_with(settings);
+        let vertices = vertex_coords(
+            self.con(),
+            triangulation.all_vertices(self.con()),
+            projection_type,
+            settings,
+        );
+
+        // Unrolls each triangle into three separate vertices.
+        let mut positions = Vec::with_capacity(triangulation.triangles.len());
+        let mut normals = Vec::with_capacity(triangulation.triangles.len());
+        for triangle in triangulation.triangles() {
+            let mut triangle = triangle.map(|idx| vertices[idx]);
+
+            // Keeps the normals consistent under reflections.
+            if settings.coord_convention.flips_winding() {
+                triangle.swap(1, 2);
+            }
+
+            let normal = triangle_normal(triangle);
+
+            for v in triangle {
+                positions.push(v);
+                normals.push(normal);
+            }
+        }
+
+        // Builds the actual mesh.
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 1.0]; positions.len()]);
+        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+
+        mesh
+    }
+
+    /// Builds the mesh of a polytope with every face colored according to its
+    /// index, cycling through the given palette. The vertices aren't shared
+    /// between triangles, so that every face can get its own color.
+    fn mesh_with_colors(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+        colors: &[[f32; 4]],
+    ) -> Mesh {
+        // If there's no vertices or colors, returns an empty mesh.
+        if self.vertex_count() == 0 || colors.is_empty() {
+            return empty_mesh();
+        }
+
+        let triangulation = self.triangulation_with(settings);
+        let vertices = vertex_coords(
+            self.con(),
+            triangulation.all_vertices(self.con()),
+            projection_type,
+            settings,
+        );
+
+        // Unrolls each triangle into three separate vertices, colored after the
+        // face they come from.
+        let mut positions = Vec::with_capacity(triangulation.triangles.len());
+        let mut normals = Vec::with_capacity(triangulation.triangles.len());
+        let mut vertex_colors = Vec::with_capacity(triangulation.triangles.len());
+        for (range, &color) in triangulation.face_ranges.iter().zip(colors.iter().cycle()) {
+            let triangles = &triangulation.triangles[range.start * 3..range.end * 3];
+            for triangle in triangles.chunks_exact(3) {
+                let mut triangle = [0, 1, 2].map(|i| vertices[triangle[i] as usize]);
+
+                // Keeps the normals consistent under reflections.
+                if settings.coord_convention.flips_winding() {
+                    triangle.swap(1, 2);
+                }
+
+                let normal = triangle_normal(triangle);
+                for v in triangle {
+                    positions.push(v);
+                    normals.push(normal);
+                    vertex_colors.push(color);
+                }
+            }
+        }
+
+        // Builds the actual mesh.
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 1.0]; positions.len()]);
+        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.set_attribute(Mesh::ATTRIBUTE_COLOR, vertex_colors);
+
+        mesh
+    }
+
+    /// Builds both the mesh and the wireframe of a polytope. This only projects
+    /// the vertices once, so it's faster than calling [`Self::mesh`] and
+    /// [`Self::wireframe`] separately.
+    fn mesh_and_wireframe(
+        &self,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> (Mesh, Mesh) {
+        // If there's no vertices, returns empty meshes.
+        if self.vertex_count() == 0 {
+            return (empty_mesh(), empty_mesh());
+        }
+
+        let vertices = self.vertex_coords(projection_type, settings);
+        let triangulation = self.triangulation_with(settings);
+        (
+            mesh_with_coords(
+                self.con(),
+                vertices.clone(),
+                triangulation,
+                projection_type,
+                settings,
+            ),
+            wireframe_with_coords(self.con(), vertices, settings),
+        )
+    }
+
+    /// Exports the triangulated faces of the polytope as a Wavefront OBJ file,
+    /// after projecting them down into 3D.
+    fn to_obj(&self, projection_type: ProjectionType, settings: &RenderSettings) -> String {
+        use std::fmt::Write;
+
+        let triangulation = self.triangulation_with(settings);
+        let vertices = vertex_coords(
+            self.con(),
+            triangulation.all_vertices(self.con()),
+            projection_type,
+            settings,
+        );
+        let mut obj = String::new();
+
+        // Writing into a string never fails.
+        for [x, y, z] in vertices {
+            writeln!(obj, "v {} {} {}", x, y, z).unwrap();
+        }
+
+        // OBJ indices start at 1. We keep the normals consistent under
+        // reflections.
+        for [i, mut j, mut k] in triangulation.triangles() {
+            if settings.coord_convention.flips_winding() {
+                std::mem::swap(&mut j, &mut k);
+            }
+
+            writeln!(obj, "f {} {} {}", i + 1, j + 1, k + 1).unwrap();
+        }
+
+        obj
+    }
+
+    /// Exports the wireframe of the polytope as an SVG image with the given
+    /// dimensions. The vertices are projected down into 3D, and then the third
+    /// coordinate is dropped. This is most useful for polygons and polyhedra.
+    fn to_svg(
+        &self,
+        width: u32,
+        height: u32,
+        projection_type: ProjectionType,
+        settings: &RenderSettings,
+    ) -> String {
+        use std::fmt::Write;
+
+        let mut svg = String::new();
+
+        // Writing into a string never fails.
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        )
+        .unwrap();
+
+        let vertices = self.vertex_coords(projection_type, settings);
+        if let Some(edges) = self.get_element_list(2).filter(|_| !vertices.is_empty()) {
+            // The bounding box of the projected vertices.
+            let mut min = [f32::INFINITY; 2];
+            let mut max = [f32::NEG_INFINITY; 2];
+            for v in &vertices {
+                for i in 0..2 {
+                    min[i] = min[i].min(v[i]);
+                    max[i] = max[i].max(v[i]);
+                }
+            }
+
+            // We fit the bounding box into the image, leaving a small margin.
+            let (width, height) = (width as f32, height as f32);
+            let margin = 0.05 * width.min(height);
+            let size = (max[0] - min[0]).max(max[1] - min[1]).max(EPS as f32);
+            let scale = (width.min(height) - 2.0 * margin) / size;
+            let center = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+
+            // The y axis points downwards in SVG.
+            let svg_point = |v: [f32; 3]| {
+                (
+                    width / 2.0 + (v[0] - center[0]) * scale,
+                    height / 2.0 - (v[1] - center[1]) * scale,
+                )
+            };
+
+            for edge in edges {
+                let (x1, y1) = svg_point(vertices[edge.subs[0]]);
+                let (x2, y2) = svg_point(vertices[edge.subs[1]]);
+
+                writeln!(
+                    svg,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="black"/>"#,
+                    x1, y1, x2, y2
+                )
+                .unwrap();
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Builds a mesh consisting of a single point at every vertex of the
+    /// polytope. Unlike the vertices of [`Self::mesh`], this doesn't include
+    /// any of the extra vertices added by the triangulation.
+    fn vertices_mesh(&self, projection_type: ProjectionType, settings: &RenderSettings) -> Mesh {
+        // If there's no vertices, returns an empty mesh.
+        if self.vertex_count() == 0 {
+            return empty_mesh();
+        }
+
+        let vertices = self.vertex_coords(projection_type, settings);
+        let vertex_count = vertices.len();
+
+        // Sets the mesh attributes.
+        let mut mesh = Mesh::new(PrimitiveTopology::PointList);
+        mesh.set_attribute(Mesh::ATTRIBUTE_NORMAL, normals(&vertices));
+        mesh.set_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+        mesh.set_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0; 2]; vertex_count]);
+
+        mesh
+    }
+
+    /// Builds a mesh consisting of a single point at the midpoint of every
+    /// edge of the polytope, so that edges can be picked out individually.
+    fn edge_midpoints(&self, projection_type: ProjectionType, settings: &RenderSettings) -> Mesh {
+        edge_midpoints_with_coords(self.con(), &self.vertex_coords(projection_type, settings))
+    }
+}
+
+impl<U: ConcretePolytope> Renderable for U {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miratope_core::file::FromFile;
+
+    /// Returns the flattened vertices of a regular `{n / d}` polygon with unit
+    /// circumradius.
+    fn star_points(n: usize, d: usize) -> Vec<[f32; 2]> {
+        (0..n)
+            .map(|k| {
+                let angle = std::f32::consts::TAU * (k * d) as f32 / n as f32;
+                [angle.cos(), angle.sin()]
+            })
+            .collect()
+    }
+
+    /// Returns the total area of a set of triangles in the plane.
+    fn area<I: IntoIterator<Item = [[f32; 2]; 3]>>(triangles: I) -> f32 {
+        triangles
+            .into_iter()
+            .map(|[a, b, c]| {
+                ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])).abs() / 2.0
+            })
+            .sum()
+    }
+
+    /// Checks that convex polygons are told apart from star polygons and from
+    /// polygons with reflex or straight angles.
+    #[test]
+    fn is_convex() {
+        assert!(super::is_convex(&star_points(4, 1)));
+        assert!(super::is_convex(&star_points(6, 1)));
+        assert!(!super::is_convex(&star_points(5, 2)));
+
+        // A quadrilateral with a reflex angle.
+        let dart = [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [1.0, 2.0]];
+        assert!(!super::is_convex(&dart));
+
+        // A triangle with an extra vertex on one of its edges.
+        let straight = [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [1.0, 1.0]];
+        assert!(!super::is_convex(&straight));
+    }
+
+    /// The fan triangulation of a hexagon should cover the same area as the one
+    /// given by the tessellator.
+    #[test]
+    fn hexagon_fan() {
+        let hexagon = Concrete::polygon(6);
+        let triangulation = hexagon.triangulation();
+        assert_eq!(triangulation.triangle_count(), 4);
+
+        let fan_area = area(triangulation.triangles().map(|triangle| {
+            triangle.map(|idx| {
+                let p = &hexagon.vertices[idx];
+                [p[0] as f32, p[1] as f32]
+            })
+        }));
+
+        let path = path(&star_points(6, 1));
+        let mut geometry: VertexBuffers<[f32; 2], u32> = VertexBuffers::new();
+        FillTessellator::new()
+            .tessellate_path(
+                &path,
+                &FillOptions::default(),
+                &mut BuffersBuilder::new(&mut geometry, |vertex: FillVertex<'_>| {
+                    vertex.position().to_array()
+                }),
+            )
+            .unwrap();
+        let lyon_area = area(
+            geometry
+                .indices
+                .chunks_exact(3)
+                .map(|t| [0, 1, 2].map(|i| geometry.vertices[t[i] as usize])),
+        );
+
+        assert!((fan_area - lyon_area).abs() < 1e-4);
+    }
+
+    /// A polytope seen from far away should only be drawn as a wireframe.
+    #[test]
+    fn lod_mesh() {
+        let settings = RenderSettings {
+            wireframe_distance: Some(10.0),
+            ..Default::default()
+        };
+        let cube = Concrete::cube();
+
+        assert_eq!(
+            cube.lod_mesh(ProjectionType::Perspective, &settings, 100.0)
+                .primitive_topology(),
+            PrimitiveTopology::LineList
+        );
+        assert_eq!(
+            cube.lod_mesh(ProjectionType::Perspective, &settings, 1.0)
+                .primitive_topology(),
+            PrimitiveTopology::TriangleList
+        );
+    }
+
+    /// A triangulation can only be used for the polytope it was computed for.
+    #[test]
+    fn mesh_from() {
+        let settings = Default::default();
+        let cube = Concrete::cube();
+        let tet = Concrete::tetrahedron();
+
+        assert!(cube
+            .mesh_from(cube.triangulation(), ProjectionType::Perspective, &settings)
+            .is_some());
+        assert!(tet
+            .mesh_from(cube.triangulation(), ProjectionType::Perspective, &settings)
+            .is_none());
+    }
+
+    /// Vertices at the projection singularity, or with non-finite coordinates,
+    /// should still be projected into finite points.
+    #[test]
+    fn vertex_coords_singularity() {
+        let mut poly = Concrete::dyad();
+        poly.vertices = vec![
+            vec![1.0, 1.0, 1.0, 0.0, -1.0].into(),
+            vec![1.0, Float::INFINITY, 1.0, Float::NAN, 0.0].into(),
+        ];
+
+        let vertices = vertex_coords(
+            &poly,
+            poly.vertices.iter(),
+            ProjectionType::Perspective,
+            &Default::default(),
+        );
+
+        assert!(vertices.iter().flatten().all(|c| c.is_finite()));
+        assert!(vertices[0][0] > 1000.0);
+        assert_eq!(vertices[1], [0.0; 3]);
+    }
+
+    /// The center of a pentagram is only filled in under the nonzero rule.
+    #[test]
+    fn fill_rule() {
+        let pentagram = Concrete::star_polygon(5, 2);
+        let triangle_count = |fill_rule| {
+            pentagram
+                .triangulation_with(&RenderSettings {
+                    fill_rule,
+                    ..Default::default()
+                })
+                .triangle_count()
+        };
+
+        assert!(triangle_count(FillRule::NonZero) > triangle_count(FillRule::EvenOdd));
+    }
+
+    /// Star polygons need extra vertices where their edges cross, while convex
+    /// polygons don't.
+    #[test]
+    fn extra_vertex_count() {
+        let pentagram = Concrete::star_polygon(5, 2);
+        assert!(pentagram.triangulation().extra_vertex_count() > 0);
+
+        let square = Concrete::polygon(4);
+        assert_eq!(square.triangulation().extra_vertex_count(), 0);
+    }
+
+    /// A smaller fill tolerance should never give fewer triangles.
+    #[test]
+    fn fill_tolerance() {
+        let pentagram = Concrete::star_polygon(5, 2);
+        let triangle_count = |fill_tolerance| {
+            pentagram
+                .triangulation_with(&RenderSettings {
+                    fill_tolerance,
+                    ..Default::default()
+                })
+                .triangle_count()
+        };
+
+        assert!(triangle_count(1e-4) >= triangle_count(0.5));
+    }
+
+    /// Returns the number of indices of a mesh.
+    fn index_count(mesh: &Mesh) -> usize {
+        match mesh.indices() {
+            Some(Indices::U16(indices)) => indices.len(),
+            Some(Indices::U32(indices)) => indices.len(),
+            None => 0,
+        }
+    }
+
+    /// Vertices within the merging distance should be merged into the first of
+    /// them, while the rest are kept apart.
+    #[test]
+    fn merge_close_vertices() {
+        let vertices = [
+            [0.0, 0.0, 0.0],
+            [0.01, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 0.01, 0.0],
+        ];
+
+        assert_eq!(
+            super::merge_close_vertices(&vertices, 0.1),
+            vec![0, 0, 2, 2]
+        );
+    }
+
+    /// Decimating the wireframe of a polytope with many nearly coincident
+    /// vertices should get rid of edges.
+    #[test]
+    fn wireframe_lod() {
+        let mut polygon = Concrete::polygon(100);
+        polygon.scale(1e-3);
+
+        let wireframe = |wireframe_lod| {
+            polygon.wireframe(
+                ProjectionType::Perspective,
+                &RenderSettings {
+                    wireframe_lod,
+                    ..Default::default()
+                },
+            )
+        };
+        let lod = WireframeLod {
+            vertex_threshold: 10,
+            epsilon: 0.1,
+        };
+
+        assert!(index_count(&wireframe(Some(lod))) < index_count(&wireframe(None)));
+    }
+
+    /// Returns the index type of a mesh.
+    fn mesh_index_kind(mesh: &Mesh) -> Option<IndexKind> {
+        match mesh.indices()? {
+            Indices::U16(_) => Some(IndexKind::U16),
+            Indices::U32(_) => Some(IndexKind::U32),
+        }
+    }
+
+    /// 16-bit indices should be used exactly when they can index every vertex.
+    #[test]
+    fn index_kind_for_len() {
+        assert_eq!(IndexKind::for_len(0), IndexKind::U16);
+        assert_eq!(IndexKind::for_len(1 << 16), IndexKind::U16);
+        assert_eq!(IndexKind::for_len((1 << 16) + 1), IndexKind::U32);
+    }
+
+    /// The mesh, the wireframe and the edge midpoints of a polytope should
+    /// always use the same index type, even close to the limit of 16-bit
+    /// indices.
+    #[test]
+    fn index_kind() {
+        let settings = Default::default();
+
+        for (poly, kind) in [
+            (Concrete::cube(), IndexKind::U16),
+            (Concrete::polygon(60_000), IndexKind::U32),
+        ] {
+            let projection_type = ProjectionType::Perspective;
+            for mesh in [
+                poly.mesh(projection_type, &settings),
+                poly.wireframe(projection_type, &settings),
+                poly.edge_midpoints(projection_type, &settings),
+            ] {
+                assert_eq!(mesh_index_kind(&mesh), Some(kind));
+            }
+        }
+    }
+
+    /// Checks the axes of each coordinate convention, and that the winding
+    /// order is flipped exactly for those that are reflections.
+    #[test]
+    fn coord_convention() {
+        use CoordConvention::*;
+
+        let p = [1.0, 2.0, 3.0];
+        assert_eq!(YUp.apply(p), [1.0, 2.0, 3.0]);
+        assert_eq!(ZUp.apply(p), [1.0, 3.0, 2.0]);
+        assert_eq!(LeftHanded.apply(p), [1.0, 2.0, -3.0]);
+
+        for convention in [YUp, ZUp, LeftHanded] {
+            let [x, y, z] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+                .map(|v| convention.apply(v).map(|c| c as Float));
+            let det: Float = (0..3).map(|i| cross(x, y)[i] * z[i]).sum();
+
+            assert_eq!(convention.flips_winding(), det < 0.0);
+        }
+    }
+
+    /// A triangulation can only be built from indices in range.
+    #[test]
+    fn from_parts() {
+        let tet = Concrete::tetrahedron();
+
+        assert!(Triangulation::from_parts(&tet, Vec::new(), vec![[0, 1, 2], [0, 1, 3]]).is_some());
+        assert!(Triangulation::from_parts(&tet, Vec::new(), vec![[0, 1, 4]]).is_none());
+        assert!(Triangulation::from_parts(&tet, vec![Point::zeros(3)], vec![[0, 1, 4]]).is_some());
+    }
+
+    /// Checks that the normals of the faces of an octahedron point outwards,
+    /// both for the octahedron and for its mirror image, whose faces are
+    /// oriented the other way around.
+    #[test]
+    fn face_normals() {
+        let octahedron = Concrete::octahedron();
+        let mut mirror = octahedron.clone();
+        for v in &mut mirror.vertices {
+            v[0] = -v[0];
+        }
+
+        for poly in [octahedron, mirror] {
+            let faces = poly.oriented_faces().unwrap();
+            let normals = super::face_normals(&poly).unwrap();
+            assert_eq!(faces.len(), 8);
+
+            for (face, normal) in faces.iter().zip(normals) {
+                let centroid = (0..3).map(|i| {
+                    face.iter().map(|&idx| poly.vertices[idx][i]).sum::<Float>()
+                        / face.len() as Float
+                });
+
+                assert!(centroid.zip(normal).map(|(c, n)| c * n).sum::<Float>() > 0.0);
+            }
+        }
+    }
+
+    /// Every index of a triangulation should be in range of its vertices,
+    /// including the extra ones.
+    #[test]
+    fn all_vertices() {
+        let pentagram = Concrete::star_polygon(5, 2);
+        let triangulation = pentagram.triangulation();
+        let vertex_count = triangulation.all_vertices(&pentagram).count();
+
+        assert!(vertex_count > pentagram.vertex_count());
+        assert!(triangulation
+            .triangles()
+            .flatten()
+            .all(|idx| idx < vertex_count));
+    }
+
+    /// Returns the number of values of a mesh attribute, or 0 if it isn't set.
+    fn attribute_len(mesh: &Mesh, name: &'static str) -> usize {
+        mesh.attribute(name).map_or(0, |values| values.len())
+    }
+
+    /// A cube has a midpoint for each of its 12 edges.
+    #[test]
+    fn edge_midpoints() {
+        let midpoints =
+            Concrete::cube().edge_midpoints(ProjectionType::Perspective, &Default::default());
+
+        assert_eq!(midpoints.primitive_topology(), PrimitiveTopology::PointList);
+        assert_eq!(attribute_len(&midpoints, Mesh::ATTRIBUTE_POSITION), 12);
+    }
+
+    /// Returns the raw bytes of a mesh attribute, if it's set.
+    fn attribute_bytes<'a>(mesh: &'a Mesh, name: &'static str) -> Option<&'a [u8]> {
+        mesh.attribute(name).map(|values| values.get_bytes())
+    }
+
+    /// Returns the indices of a mesh as 32-bit integers, if it has any.
+    fn indices(mesh: &Mesh) -> Option<Vec<u32>> {
+        match mesh.indices()? {
+            Indices::U16(indices) => Some(indices.iter().map(|&idx| idx as u32).collect()),
+            Indices::U32(indices) => Some(indices.clone()),
+        }
+    }
+
+    /// Building the mesh and the wireframe at once should give the same result
+    /// as building them separately.
+    #[test]
+    fn mesh_and_wireframe() {
+        let settings = Default::default();
+
+        for poly in [Concrete::cube(), Concrete::star_polygon(5, 2)] {
+            for projection_type in [ProjectionType::Perspective, ProjectionType::Orthogonal] {
+                let (mesh, wireframe) = poly.mesh_and_wireframe(projection_type, &settings);
+
+                for (joint, separate) in [
+                    (mesh, poly.mesh(projection_type, &settings)),
+                    (wireframe, poly.wireframe(projection_type, &settings)),
+                ] {
+                    assert_eq!(
+                        attribute_bytes(&joint, Mesh::ATTRIBUTE_POSITION),
+                        attribute_bytes(&separate, Mesh::ATTRIBUTE_POSITION)
+                    );
+                    assert_eq!(indices(&joint), indices(&separate));
+                }
+            }
+        }
+    }
+
+    /// The OBJ file of a cube should have a line for each of its vertices and
+    /// triangles, and every face should only refer to vertices in the file.
+    #[test]
+    fn to_obj() {
+        let cube = Concrete::cube();
+        let obj = cube.to_obj(ProjectionType::Perspective, &Default::default());
+        let lines = |prefix: &'static str| obj.lines().filter(move |line| line.starts_with(prefix));
+
+        let vertex_count = lines("v ").count();
+        assert_eq!(vertex_count, cube.vertex_count());
+        assert_eq!(lines("f ").count(), cube.triangulation().triangle_count());
+
+        for line in lines("f ") {
+            for idx in line.split_whitespace().skip(1) {
+                let idx: usize = idx.parse().unwrap();
+                assert!((1..=vertex_count).contains(&idx));
+            }
+        }
+    }
+
+    /// A square should be drawn with one line per edge, while the nullitope
+    /// should still give a valid, empty image.
+    #[test]
+    fn to_svg() {
+        let settings = Default::default();
+        let projection_type = ProjectionType::Perspective;
+
+        let square = Concrete::polygon(4).to_svg(100, 100, projection_type, &settings);
+        assert!(square.starts_with("<svg"));
+        assert!(square.trim_end().ends_with("</svg>"));
+        assert_eq!(square.matches("<line").count(), 4);
+
+        let nullitope = Concrete::nullitope().to_svg(100, 100, projection_type, &settings);
+        assert!(nullitope.starts_with("<svg"));
+        assert!(nullitope.trim_end().ends_with("</svg>"));
+        assert_eq!(nullitope.lines().count(), 2);
+    }
+
+    /// A "triangle" with collinear vertices has no area, so it shouldn't be
+    /// triangulated at all.
+    #[test]
+    fn collinear_triangle() {
+        let triangle = Concrete::from_off("2OFF\n3 1\n0 0\n1 0\n2 0\n3 0 1 2\n").unwrap();
+        assert_eq!(triangle.triangulation().triangle_count(), 0);
+    }
+
+    /// Putting the faces of a pentagram prism together, whether in parallel or
+    /// not, should give the triangles and extra vertices of both bases plus
+    /// two triangles for each of the five rectangles.
+    #[test]
+    fn merge_faces() {
+        let pentagram = Concrete::star_polygon(5, 2);
+        let prism = pentagram.prism();
+        let base = pentagram.triangulation();
+        let triangulation = prism.triangulation();
+
+        assert_eq!(
+            triangulation.triangle_count(),
+            2 * base.triangle_count() + 5 * 2
+        );
+        assert_eq!(
+            triangulation.extra_vertex_count(),
+            2 * base.extra_vertex_count()
+        );
+
+        // Every index should be in range, and every extra vertex should be
+        // used by some triangle.
+        let vertex_count = triangulation.all_vertices(&prism).count();
+        let used: HashSet<_> = triangulation.triangles().flatten().collect();
+        assert!(used.iter().all(|&idx| idx < vertex_count));
+        assert!((prism.vertex_count()..vertex_count).all(|idx| used.contains(&idx)));
+    }
+
+    /// A flat mesh should have three vertices for every triangle, and no
+    /// indices at all.
+    #[test]
+    fn flat_mesh() {
+        let cube = Concrete::cube();
+        let mesh = cube.flat_mesh(ProjectionType::Perspective, &Default::default());
+
+        assert_eq!(
+            attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION),
+            3 * cube.triangulation().triangle_count()
+        );
+        assert!(mesh.indices().is_none());
+    }
+
+    /// The vertices of a cube should be drawn as 8 points.
+    #[test]
+    fn vertices_mesh() {
+        let mesh = Concrete::cube().vertices_mesh(ProjectionType::Perspective, &Default::default());
+
+        assert_eq!(mesh.primitive_topology(), PrimitiveTopology::PointList);
+        assert_eq!(attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION), 8);
+    }
+
+    /// A cube with unit edges is bounded by the box between `-0.5` and `0.5`,
+    /// while the nullitope has no bounding box.
+    #[test]
+    fn bounds() {
+        let settings = Default::default();
+        let projection_type = ProjectionType::Orthogonal;
+
+        let (min, max) = Concrete::cube().bounds(projection_type, &settings).unwrap();
+        for i in 0..3 {
+            assert!((min[i] + 0.5).abs() < 1e-6);
+            assert!((max[i] - 0.5).abs() < 1e-6);
+        }
+
+        assert!(Concrete::nullitope()
+            .bounds(projection_type, &settings)
+            .is_none());
+    }
+
+    /// A centered cube should have its centroid at the origin, while the
+    /// nullitope has no centroid.
+    #[test]
+    fn centroid() {
+        let settings = Default::default();
+        let projection_type = ProjectionType::Perspective;
+
+        let centroid = Concrete::cube()
+            .centroid(projection_type, &settings)
+            .unwrap();
+        assert!(centroid.iter().all(|c| c.abs() < 1e-6));
+
+        assert!(Concrete::nullitope()
+            .centroid(projection_type, &settings)
+            .is_none());
+    }
+
+    /// The skeleton of a cube should be empty up to rank 0, consist of points
+    /// up to rank 1, and of lines from then onwards.
+    #[test]
+    fn skeleton() {
+        let settings = Default::default();
+        let cube = Concrete::cube();
+        let skeleton = |max_rank| cube.skeleton(ProjectionType::Perspective, &settings, max_rank);
+
+        assert_eq!(index_count(&skeleton(0)), 0);
+        assert_eq!(
+            skeleton(1).primitive_topology(),
+            PrimitiveTopology::PointList
+        );
+        for max_rank in 2..=4 {
+            assert_eq!(
+                skeleton(max_rank).primitive_topology(),
+                PrimitiveTopology::LineList
+            );
+        }
+    }
+
+    /// Storing the triangulation of a polytope and loading it back should give
+    /// the same mesh as triangulating it again.
+    #[test]
+    fn triangulation_data() {
+        let settings = Default::default();
+        let projection_type = ProjectionType::Perspective;
+
+        for poly in [Concrete::cube(), Concrete::star_polygon(5, 2)] {
+            let data = ron::to_string(&poly.triangulation_data(&settings)).unwrap();
+            let data: TriangulationData = ron::from_str(&data).unwrap();
+
+            let mesh = poly.mesh(projection_type, &settings);
+            let loaded = poly
+                .mesh_from_data(data, projection_type, &settings)
+                .unwrap();
+
+            assert_eq!(
+                attribute_bytes(&loaded, Mesh::ATTRIBUTE_POSITION),
+                attribute_bytes(&mesh, Mesh::ATTRIBUTE_POSITION)
+            );
+            assert_eq!(indices(&loaded), indices(&mesh));
+        }
+    }
+
+    /// Every vertex of the colored mesh of a cube should get a color, cycling
+    /// through the palette face by face. Each face of a cube is made out of two
+    /// triangles, so six vertices.
+    #[test]
+    fn mesh_with_colors() {
+        let colors: [[f32; 4]; 2] = [[1.0, 0.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0]];
+        let mesh = Concrete::cube().mesh_with_colors(
+            ProjectionType::Perspective,
+            &Default::default(),
+            &colors,
+        );
+
+        let vertex_count = attribute_len(&mesh, Mesh::ATTRIBUTE_POSITION);
+        assert_eq!(vertex_count, 6 * 6);
+        assert_eq!(attribute_len(&mesh, Mesh::ATTRIBUTE_COLOR), vertex_count);
+
+        // Colors are stored as four 32-bit floats each.
+        let vertex_colors = attribute_bytes(&mesh, Mesh::ATTRIBUTE_COLOR).unwrap();
+        for (idx, color) in vertex_colors.chunks_exact(16).enumerate() {
+            let expected: Vec<u8> = colors[(idx / 6) % 2]
+                .iter()
+                .flat_map(|c| c.to_ne_bytes())
+                .collect();
+            assert_eq!(color, expected.as_slice());
+        }
+    }
+
+    /// Building the meshes of the smallest polytopes shouldn't panic, and
+    /// shouldn't draw anything.
+    #[test]
+    fn small_polytopes() {
+        let settings = Default::default();
+
+        for poly in [Concrete::nullitope(), Concrete::point()] {
+            for projection_type in [ProjectionType::Perspective, ProjectionType::Orthogonal] {
+                let (mesh, wireframe) = poly.mesh_and_wireframe(projection_type, &settings);
+
+                for mesh in [
+                    mesh,
+                    wireframe,
+                    poly.mesh(projection_type, &settings),
+                    poly.wireframe(projection_type, &settings),
+                ] {
+                    assert_eq!(index_count(&mesh), 0);
+                }
+            }
+        }
+    }
+
+    /// Doing the projection math in `Float` shouldn't change the projection of
+    /// a tesseract by more than the precision of an `f32`, in comparison to
+    /// doing it in `f32` throughout.
+    #[test]
+    fn vertex_coords_precision() {
+        let tesseract = Concrete::hypercube(5);
+        let settings = RenderSettings::default();
+        let convention = settings.coord_convention;
+
+        // The projection as it used to be computed.
+        let (min, max) = tesseract
+            .vertices
+            .iter()
+            .map(|p| p[3] as f32)
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), w| {
+                (min.min(w), max.max(w))
+            });
+        let dist = (min - 1.0).abs().max(max + 1.0).abs();
+        let old = tesseract.vertices.iter().map(|p| {
+            let factor: f32 = p.iter().skip(3).map(|&x| x as f32 + dist).product();
+            convention.apply([0, 1, 2].map(|i| p[i] as f32 / factor))
+        });
+
+        let new = tesseract.vertex_coords(ProjectionType::Perspective, &settings);
+        assert_eq!(new.len(), tesseract.vertex_count());
+        for (old, new) in old.zip(new) {
+            for i in 0..3 {
+                assert!((old[i] - new[i]).abs() < 1e-6);
+            }
+        }
+    }
+
+    /// Moving a vertex and only triangulating the faces that contain it again
+    /// should give the same result as triangulating the whole polytope again.
+    #[test]
+    fn update_vertex() {
+        let settings = Default::default();
+
+        for mut poly in [Concrete::cube(), Concrete::star_polygon(5, 2).prism()] {
+            let mut triangulation = poly.triangulation_with(&settings);
+
+            // Moves the vertex within the plane of one of its faces, so that
+            // some of the faces stay planar.
+            let mut new = poly.vertices[0].clone();
+            new[0] *= 1.2;
+            new[1] *= 1.2;
+            poly.update_vertex(&mut triangulation, 0, new, &settings);
+
+            let rebuilt = poly.triangulation_with(&settings);
+            assert!(triangulation.triangles().eq(rebuilt.triangles()));
+            assert_eq!(triangulation.extra_vertices(), rebuilt.extra_vertices());
+        }
+    }
+}
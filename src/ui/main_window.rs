@@ -5,7 +5,7 @@ use std::path::PathBuf;
 use super::config::{MeshColor, WfColor};
 use super::right_panel::ElementTypesRes;
 use super::{camera::ProjectionType, top_panel::SectionState};
-use crate::mesh::Renderable;
+use crate::mesh::{RenderSettings, Renderable};
 use crate::Concrete;
 
 use bevy::prelude::*;
@@ -22,7 +22,8 @@ impl Plugin for MainWindowPlugin {
             .add_system(update_scale_factor.system())
             .add_system_to_stage(CoreStage::PostUpdate, update_changed_polytopes.system())
             .add_system_to_stage(CoreStage::PostUpdate, update_changed_color.system())
-            .init_resource::<PolyName>();
+            .init_resource::<PolyName>()
+            .init_resource::<RenderSettings>();
     }
 }
 
@@ -80,6 +81,7 @@ pub fn update_changed_polytopes(
     name: Res<'_, PolyName>,
 
     orthogonal: Res<'_, ProjectionType>,
+    render_settings: Res<'_, RenderSettings>,
 ) {
     for (poly, mesh_handle, children) in polies.iter() {
         if cfg!(debug_assertions) {
@@ -92,12 +94,13 @@ pub fn update_changed_polytopes(
             element_types.main_updating = false;
         }
 
-        *meshes.get_mut(mesh_handle).unwrap() = poly.mesh(*orthogonal);
+        let (mesh, wireframe) = poly.mesh_and_wireframe(*orthogonal, &render_settings);
+        *meshes.get_mut(mesh_handle).unwrap() = mesh;
 
         // Updates all wireframes.
         for child in children.iter() {
             if let Ok(wf_handle) = wfs.get_component::<Handle<Mesh>>(*child) {
-                *meshes.get_mut(wf_handle).unwrap() = poly.wireframe(*orthogonal);
+                *meshes.get_mut(wf_handle).unwrap() = wireframe.clone();
             }
         }
 